@@ -0,0 +1,201 @@
+// src/changed_files.rs
+//
+// Shells out to the `git` binary rather than linking libgit2 or gitoxide: the diff has to
+// include uncommitted and untracked files and honour the user's excludes, which `git` gives
+// us as-is, and `diff_base` is only useful where git is installed anyway.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use serde::Serialize;
+use syncable_cli::analyzer::security::SecurityFinding;
+use tempfile::TempDir;
+
+/// Which files a `diff_base` scan covered.
+#[derive(Debug, Serialize)]
+pub struct DiffScope {
+    pub base: String,
+    pub changed_files: Vec<String>,
+}
+
+fn git(project_path: &Path, args: &[&str]) -> Result<String, String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(project_path)
+        .args(args)
+        .output()
+        .map_err(|e| format!("Failed to run git: {}", e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Runs a git command that lists paths with `-z`, so paths with non-ASCII characters or
+/// newlines come back verbatim instead of quoted and escaped.
+fn git_paths(project_path: &Path, args: &[&str]) -> Result<Vec<String>, String> {
+    let output = git(project_path, args)?;
+    Ok(output
+        .split('\0')
+        .filter(|path| !path.is_empty())
+        .map(str::to_string)
+        .collect())
+}
+
+/// Resolves `base` to a commit id, so user input never reaches git as an option.
+fn resolve(project_path: &Path, base: &str) -> Result<String, String> {
+    if base.is_empty() || base.starts_with('-') {
+        return Err(format!("Invalid diff_base '{}': expected a git ref or commit", base));
+    }
+    let revision = format!("{}^{{commit}}", base);
+    git(project_path, &["rev-parse", "--verify", "--quiet", "--end-of-options", &revision])
+        .ok()
+        .and_then(|output| output.lines().next().map(str::to_string))
+        .ok_or_else(|| format!("Invalid diff_base '{}': not a commit in {}", base, project_path.display()))
+}
+
+/// Files under `project_path` added or modified since `base`, including uncommitted and
+/// untracked ones, relative to `project_path`.
+pub fn since(project_path: &Path, base: &str) -> Result<Vec<String>, String> {
+    let commit = resolve(project_path, base)?;
+    let mut files =
+        git_paths(project_path, &["diff", "-z", "--name-only", "--relative", "--diff-filter=ACMR", &commit, "--"])?;
+    files.extend(git_paths(project_path, &["ls-files", "-z", "--others", "--exclude-standard"])?);
+    files.sort();
+    files.dedup();
+    Ok(files)
+}
+
+/// Copies `files` into a temporary directory with the same layout, so the analyzers can
+/// scan just those files without walking the whole project.
+pub fn stage(project_path: &Path, files: &[String]) -> Result<TempDir, String> {
+    let dir = tempfile::Builder::new()
+        .prefix("syncable-diff-")
+        .tempdir()
+        .map_err(|e| format!("Failed to create staging directory: {}", e))?;
+    let root = project_path
+        .canonicalize()
+        .map_err(|e| format!("Failed to resolve {}: {}", project_path.display(), e))?;
+    for file in files {
+        let source = project_path.join(file);
+        // Only regular files inside the project: a symlink, or a path through a symlinked
+        // directory, could pull in files from anywhere on disk.
+        let is_regular = std::fs::symlink_metadata(&source).is_ok_and(|meta| meta.file_type().is_file());
+        let inside = source.canonicalize().is_ok_and(|path| path.starts_with(&root));
+        if !is_regular || !inside {
+            continue;
+        }
+        let target = dir.path().join(file);
+        if let Some(parent) = target.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+        }
+        std::fs::copy(&source, &target)
+            .map_err(|e| format!("Failed to stage {}: {}", source.display(), e))?;
+    }
+    Ok(dir)
+}
+
+/// Points a finding from the staging directory back at the project.
+fn unstage(finding: &mut SecurityFinding, staged: &Path, project_path: &Path) {
+    if let Some(relative) = finding
+        .file_path
+        .as_ref()
+        .and_then(|file| file.strip_prefix(staged).ok())
+    {
+        finding.file_path = Some(project_path.join(relative));
+    }
+    let staged = staged.to_string_lossy();
+    if finding.id.contains(staged.as_ref()) {
+        finding.id = finding.id.replace(staged.as_ref(), &project_path.to_string_lossy());
+    }
+    finding.description = finding.description.replace(staged.as_ref(), &project_path.to_string_lossy());
+}
+
+/// The paths a scan should use: the staging directory holds the files, the project owns them.
+pub struct ScanRoot {
+    pub path: PathBuf,
+    staged: Option<TempDir>,
+}
+
+impl ScanRoot {
+    pub fn project(project_path: &Path) -> Self {
+        Self { path: project_path.to_path_buf(), staged: None }
+    }
+
+    pub fn staged(dir: TempDir) -> Self {
+        let path = dir.path().canonicalize().unwrap_or_else(|_| dir.path().to_path_buf());
+        Self { path, staged: Some(dir) }
+    }
+
+    /// Maps findings scanned under this root back to `project_path`.
+    pub fn unstage_all(&self, findings: &mut [SecurityFinding], project_path: &Path) {
+        if self.staged.is_some() {
+            for finding in findings {
+                unstage(finding, &self.path, project_path);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_bases_that_look_like_options() {
+        let project = tempfile::tempdir().unwrap();
+        for base in ["--output=/tmp/pwned", "-p", ""] {
+            let error = since(project.path(), base).unwrap_err();
+            assert!(error.starts_with("Invalid diff_base"), "{}", error);
+        }
+    }
+
+    #[test]
+    fn lists_non_ascii_paths_verbatim() {
+        let project = tempfile::tempdir().unwrap();
+        let run = |args: &[&str]| {
+            let status = Command::new("git")
+                .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+                .args(args)
+                .current_dir(project.path())
+                .output()
+                .unwrap()
+                .status;
+            assert!(status.success(), "git {:?} failed", args);
+        };
+        std::fs::write(project.path().join("naïve.js"), "one").unwrap();
+        run(&["init", "-q"]);
+        run(&["add", "."]);
+        run(&["commit", "-q", "-m", "initial"]);
+        std::fs::write(project.path().join("naïve.js"), "two").unwrap();
+        std::fs::write(project.path().join("café.js"), "new").unwrap();
+
+        let files = since(project.path(), "HEAD").unwrap();
+        assert_eq!(files, ["café.js", "naïve.js"]);
+        let staged = stage(project.path(), &files).unwrap();
+        assert!(staged.path().join("café.js").is_file());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn stage_skips_symlinks() {
+        let outside = tempfile::tempdir().unwrap();
+        std::fs::write(outside.path().join("secret.txt"), "outside").unwrap();
+        let project = tempfile::tempdir().unwrap();
+        std::fs::write(project.path().join("app.js"), "inside").unwrap();
+        std::os::unix::fs::symlink(outside.path().join("secret.txt"), project.path().join("link.txt")).unwrap();
+        std::os::unix::fs::symlink(outside.path(), project.path().join("linked")).unwrap();
+
+        let files = ["app.js", "link.txt", "linked/secret.txt"].map(String::from);
+        let staged = stage(project.path(), &files).unwrap();
+
+        assert!(staged.path().join("app.js").is_file());
+        assert!(!staged.path().join("link.txt").exists());
+        assert!(!staged.path().join("linked").exists());
+    }
+}
@@ -0,0 +1,174 @@
+// src/cwe.rs
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+use syncable_cli::analyzer::security::turbo::results::SecurityReport;
+
+use crate::owasp;
+
+/// Names from the CWE list for the weaknesses the scanners report and the OWASP mapping covers.
+const REGISTRY: &[(u32, &str)] = &[
+    (16, "Configuration"),
+    (20, "Improper Input Validation"),
+    (22, "Improper Limitation of a Pathname to a Restricted Directory ('Path Traversal')"),
+    (74, "Improper Neutralization of Special Elements in Output Used by a Downstream Component ('Injection')"),
+    (77, "Improper Neutralization of Special Elements used in a Command ('Command Injection')"),
+    (78, "Improper Neutralization of Special Elements used in an OS Command ('OS Command Injection')"),
+    (79, "Improper Neutralization of Input During Web Page Generation ('Cross-site Scripting')"),
+    (89, "Improper Neutralization of Special Elements used in an SQL Command ('SQL Injection')"),
+    (90, "Improper Neutralization of Special Elements used in an LDAP Query ('LDAP Injection')"),
+    (91, "XML Injection (aka Blind XPath Injection)"),
+    (94, "Improper Control of Generation of Code ('Code Injection')"),
+    (95, "Improper Neutralization of Directives in Dynamically Evaluated Code ('Eval Injection')"),
+    (117, "Improper Output Neutralization for Logs"),
+    (200, "Exposure of Sensitive Information to an Unauthorized Actor"),
+    (201, "Insertion of Sensitive Information Into Sent Data"),
+    (209, "Generation of Error Message Containing Sensitive Information"),
+    (223, "Omission of Security-relevant Information"),
+    (255, "Credentials Management Errors"),
+    (256, "Plaintext Storage of a Password"),
+    (259, "Use of Hard-coded Password"),
+    (260, "Password in Configuration File"),
+    (261, "Weak Encoding for Password"),
+    (284, "Improper Access Control"),
+    (285, "Improper Authorization"),
+    (287, "Improper Authentication"),
+    (288, "Authentication Bypass Using an Alternate Path or Channel"),
+    (290, "Authentication Bypass by Spoofing"),
+    (294, "Authentication Bypass by Capture-replay"),
+    (295, "Improper Certificate Validation"),
+    (296, "Improper Following of a Certificate's Chain of Trust"),
+    (297, "Improper Validation of Certificate with Host Mismatch"),
+    (306, "Missing Authentication for Critical Function"),
+    (307, "Improper Restriction of Excessive Authentication Attempts"),
+    (310, "Cryptographic Issues"),
+    (315, "Cleartext Storage of Sensitive Information in a Cookie"),
+    (319, "Cleartext Transmission of Sensitive Information"),
+    (321, "Use of Hard-coded Cryptographic Key"),
+    (326, "Inadequate Encryption Strength"),
+    (327, "Use of a Broken or Risky Cryptographic Algorithm"),
+    (328, "Use of Weak Hash"),
+    (330, "Use of Insufficiently Random Values"),
+    (331, "Insufficient Entropy"),
+    (345, "Insufficient Verification of Data Authenticity"),
+    (352, "Cross-Site Request Forgery (CSRF)"),
+    (353, "Missing Support for Integrity Check"),
+    (359, "Exposure of Private Personal Information to an Unauthorized Actor"),
+    (384, "Session Fixation"),
+    (425, "Direct Request ('Forced Browsing')"),
+    (426, "Untrusted Search Path"),
+    (494, "Download of Code Without Integrity Check"),
+    (501, "Trust Boundary Violation"),
+    (502, "Deserialization of Untrusted Data"),
+    (520, ".NET Misconfiguration: Use of Impersonation"),
+    (521, "Weak Password Requirements"),
+    (522, "Insufficiently Protected Credentials"),
+    (526, "Cleartext Storage of Sensitive Information in an Environment Variable"),
+    (532, "Insertion of Sensitive Information into Log File"),
+    (565, "Reliance on Cookies without Validation and Integrity Checking"),
+    (611, "Improper Restriction of XML External Entity Reference"),
+    (613, "Insufficient Session Expiration"),
+    (614, "Sensitive Cookie in HTTPS Session Without 'Secure' Attribute"),
+    (620, "Unverified Password Change"),
+    (639, "Authorization Bypass Through User-Controlled Key"),
+    (640, "Weak Password Recovery Mechanism for Forgotten Password"),
+    (756, "Missing Custom Error Page"),
+    (778, "Insufficient Logging"),
+    (784, "Reliance on Cookies without Validation and Integrity Checking in a Security Decision"),
+    (798, "Use of Hard-coded Credentials"),
+    (829, "Inclusion of Functionality from Untrusted Control Sphere"),
+    (830, "Inclusion of Web Functionality from an Untrusted Source"),
+    (862, "Missing Authorization"),
+    (863, "Incorrect Authorization"),
+    (915, "Improperly Controlled Modification of Dynamically-Determined Object Attributes"),
+    (916, "Use of Password Hash With Insufficient Computational Effort"),
+    (917, "Improper Neutralization of Special Elements used in an Expression Language Statement ('Expression Language Injection')"),
+    (918, "Server-Side Request Forgery (SSRF)"),
+    (942, "Permissive Cross-domain Policy with Untrusted Domains"),
+    (943, "Improper Neutralization of Special Elements in Data Query Logic"),
+    (1004, "Sensitive Cookie Without 'HttpOnly' Flag"),
+];
+
+/// The number in a `CWE-<n>` id, ignoring case and surrounding whitespace.
+pub fn number(cwe_id: &str) -> Option<u32> {
+    cwe_id.trim().to_uppercase().strip_prefix("CWE-")?.parse().ok()
+}
+
+/// The CWE list's name for a weakness, if it is in the registry.
+pub fn name(cwe: u32) -> Option<&'static str> {
+    REGISTRY.iter().find(|(id, _)| *id == cwe).map(|(_, name)| *name)
+}
+
+/// Findings reported under one CWE, with the weakness class it rolls up to.
+#[derive(Debug, Serialize, PartialEq)]
+pub struct CweCount {
+    pub name: Option<&'static str>,
+    pub url: String,
+    pub owasp_top10: Option<&'static str>,
+    pub count: usize,
+}
+
+/// Number of findings per CWE, keyed `CWE-<n>`; findings without a valid CWE are left out.
+pub fn counts(report: &SecurityReport) -> BTreeMap<String, CweCount> {
+    let mut counts = BTreeMap::new();
+    for cwe in report.findings.iter().filter_map(|finding| finding.cwe_id.as_deref().and_then(number)) {
+        counts
+            .entry(format!("CWE-{}", cwe))
+            .or_insert_with(|| CweCount {
+                name: name(cwe),
+                url: format!("https://cwe.mitre.org/data/definitions/{}.html", cwe),
+                owasp_top10: owasp::cwe_category(cwe),
+                count: 0,
+            })
+            .count += 1;
+    }
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use syncable_cli::analyzer::security::SecuritySeverity;
+
+    use super::*;
+    use crate::test_support::{report, secret};
+
+    #[test]
+    fn registry_covers_the_owasp_mapping() {
+        for cwe in owasp::mapped_cwes() {
+            assert!(name(cwe).is_some(), "CWE-{} has no name", cwe);
+        }
+        assert!(REGISTRY.windows(2).all(|pair| pair[0].0 < pair[1].0), "registry is sorted and unique");
+    }
+
+    #[test]
+    fn counts_findings_per_cwe() {
+        let with_cwe = |rule: &str, line, cwe: Option<&str>| {
+            let mut finding = secret(rule, "config.js", line, SecuritySeverity::High);
+            finding.cwe_id = cwe.map(str::to_string);
+            finding
+        };
+        let report = report(vec![
+            with_cwe("aws-access-key", 1, Some("CWE-798")),
+            with_cwe("jwt-token", 2, Some(" cwe-798")),
+            with_cwe("private-key", 3, Some("CWE-321")),
+            with_cwe("custom-rule", 4, Some("CWE-99999")),
+            with_cwe("long-secret-value", 5, None),
+            with_cwe("legacy", 6, Some("n/a")),
+        ]);
+
+        let counts = counts(&report);
+        assert_eq!(counts.keys().collect::<Vec<_>>(), ["CWE-321", "CWE-798", "CWE-99999"]);
+        assert_eq!(
+            counts["CWE-798"],
+            CweCount {
+                name: Some("Use of Hard-coded Credentials"),
+                url: "https://cwe.mitre.org/data/definitions/798.html".to_string(),
+                owasp_top10: Some("A07:2021-Identification and Authentication Failures"),
+                count: 2,
+            }
+        );
+        assert_eq!(counts["CWE-321"].owasp_top10, Some("A02:2021-Cryptographic Failures"));
+        assert_eq!((counts["CWE-99999"].name, counts["CWE-99999"].owasp_top10), (None, None));
+    }
+}
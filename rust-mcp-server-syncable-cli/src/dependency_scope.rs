@@ -0,0 +1,142 @@
+// src/dependency_scope.rs
+
+use std::collections::{HashMap, HashSet};
+
+use syncable_cli::analyzer::dependency_parser::{
+    DependencyInfo, DependencyType, DetailedDependencyMap, Language,
+};
+
+/// Which dependencies a scan covers, selected by the `prod_only`/`dev_only` tool arguments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DependencyScope {
+    All,
+    Production,
+    Development,
+}
+
+impl DependencyScope {
+    pub fn from_flags(prod_only: Option<bool>, dev_only: Option<bool>) -> Result<Self, String> {
+        match (prod_only.unwrap_or(false), dev_only.unwrap_or(false)) {
+            (true, true) => Err("prod_only and dev_only cannot both be set".to_string()),
+            (true, false) => Ok(Self::Production),
+            (false, true) => Ok(Self::Development),
+            (false, false) => Ok(Self::All),
+        }
+    }
+
+    /// Optional dependencies ship with the product, so they count as production.
+    fn includes_dev(self, is_dev: bool) -> bool {
+        match self {
+            Self::All => true,
+            Self::Production => !is_dev,
+            Self::Development => is_dev,
+        }
+    }
+
+    /// Drops dependencies outside the scope and returns the names of the packages dropped.
+    pub fn retain(self, dependencies: &mut HashMap<Language, Vec<DependencyInfo>>) -> HashSet<String> {
+        let mut excluded = HashSet::new();
+        for deps in dependencies.values_mut() {
+            deps.retain(|dep| {
+                let keep = self.includes_dev(matches!(dep.dep_type, DependencyType::Dev));
+                if !keep {
+                    excluded.insert(dep.name.clone());
+                }
+                keep
+            });
+        }
+        dependencies.retain(|_, deps| !deps.is_empty());
+        // A package kept in another ecosystem or role is still scanned.
+        for dep in dependencies.values().flatten() {
+            excluded.remove(&dep.name);
+        }
+        excluded
+    }
+
+    pub fn retain_detailed(self, dependencies: &mut DetailedDependencyMap) {
+        dependencies.retain(|_, dep| self.includes_dev(dep.is_dev));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syncable_cli::analyzer::dependency_parser::LegacyDependencyInfo;
+
+    use super::*;
+
+    fn dependency(name: &str, dep_type: DependencyType) -> DependencyInfo {
+        DependencyInfo {
+            name: name.to_string(),
+            version: "1.0.0".to_string(),
+            dep_type,
+            license: "MIT".to_string(),
+            source: None,
+            language: Language::JavaScript,
+        }
+    }
+
+    fn parsed() -> HashMap<Language, Vec<DependencyInfo>> {
+        HashMap::from([
+            (
+                Language::JavaScript,
+                vec![
+                    dependency("express", DependencyType::Production),
+                    dependency("fsevents", DependencyType::Optional),
+                    dependency("jest", DependencyType::Dev),
+                ],
+            ),
+            (Language::Python, vec![dependency("pytest", DependencyType::Dev)]),
+        ])
+    }
+
+    fn names(dependencies: &HashMap<Language, Vec<DependencyInfo>>) -> Vec<&str> {
+        let mut names: Vec<&str> = dependencies.values().flatten().map(|dep| dep.name.as_str()).collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn from_flags_rejects_both_scopes() {
+        assert_eq!(DependencyScope::from_flags(None, None), Ok(DependencyScope::All));
+        assert_eq!(DependencyScope::from_flags(Some(false), Some(false)), Ok(DependencyScope::All));
+        assert_eq!(DependencyScope::from_flags(Some(true), None), Ok(DependencyScope::Production));
+        assert_eq!(DependencyScope::from_flags(None, Some(true)), Ok(DependencyScope::Development));
+        assert_eq!(
+            DependencyScope::from_flags(Some(true), Some(true)),
+            Err("prod_only and dev_only cannot both be set".to_string())
+        );
+    }
+
+    #[test]
+    fn retain_counts_optional_dependencies_as_production() {
+        let mut dependencies = parsed();
+        assert!(DependencyScope::All.retain(&mut dependencies).is_empty());
+        assert_eq!(names(&dependencies), ["express", "fsevents", "jest", "pytest"]);
+
+        let mut dependencies = parsed();
+        let excluded = DependencyScope::Production.retain(&mut dependencies);
+        assert_eq!(names(&dependencies), ["express", "fsevents"]);
+        assert_eq!(excluded, HashSet::from(["jest".to_string(), "pytest".to_string()]));
+        assert!(!dependencies.contains_key(&Language::Python), "empty languages are dropped");
+
+        let mut dependencies = parsed();
+        DependencyScope::Development.retain(&mut dependencies);
+        assert_eq!(names(&dependencies), ["jest", "pytest"]);
+    }
+
+    #[test]
+    fn retain_detailed_filters_on_is_dev() {
+        let detailed = |is_dev| LegacyDependencyInfo {
+            version: "1.0.0".to_string(),
+            is_dev,
+            license: None,
+            vulnerabilities: vec![],
+            source: "npm".to_string(),
+        };
+        let mut dependencies: DetailedDependencyMap =
+            HashMap::from([("express".to_string(), detailed(false)), ("jest".to_string(), detailed(true))]);
+
+        DependencyScope::Development.retain_detailed(&mut dependencies);
+        assert_eq!(dependencies.keys().collect::<Vec<_>>(), ["jest"]);
+    }
+}
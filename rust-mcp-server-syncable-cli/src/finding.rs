@@ -0,0 +1,61 @@
+// src/finding.rs
+
+use std::path::Path;
+
+use syncable_cli::analyzer::security::SecurityFinding;
+
+/// The rule that produced a finding.
+///
+/// Turbo ids embed the absolute path and line (`{rule}-{path}-{line}`), so the rule is the
+/// part before the file path. Classic secret ids end with the zero-based line index
+/// (`secret-{pattern}-{index}`), which is dropped; other classic ids (`env-{var}`) are kept.
+pub fn rule_id(finding: &SecurityFinding) -> String {
+    if let Some(file) = &finding.file_path {
+        if let Some(index) = finding.id.find(&format!("-{}", file.display())) {
+            return finding.id[..index].to_string();
+        }
+    }
+    if let Some(line) = finding.line_number {
+        let suffix = format!("-{}", line.saturating_sub(1));
+        if let Some(rule) = finding.id.strip_suffix(&suffix) {
+            return rule.to_string();
+        }
+    }
+    finding.id.clone()
+}
+
+/// The finding's file relative to the project, with `/` separators.
+pub fn relative_file(finding: &SecurityFinding, project_path: &Path) -> Option<String> {
+    finding.file_path.as_ref().map(|file| {
+        file.strip_prefix(project_path)
+            .unwrap_or(file)
+            .to_string_lossy()
+            .replace('\\', "/")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use syncable_cli::analyzer::security::SecuritySeverity;
+
+    use super::*;
+    use crate::test_support::secret;
+
+    #[test]
+    fn rule_id_strips_location_for_each_engine() {
+        assert_eq!(rule_id(&secret("long-secret-value", "src/app.js", 12, SecuritySeverity::High)), "long-secret-value");
+
+        let mut classic = secret("ignored", "src/app.js", 12, SecuritySeverity::High);
+        classic.id = "secret-stripe-secret-key-11".to_string();
+        assert_eq!(rule_id(&classic), "secret-stripe-secret-key");
+        // The same rule on another line shares its id.
+        classic.id = "secret-stripe-secret-key-41".to_string();
+        classic.line_number = Some(42);
+        assert_eq!(rule_id(&classic), "secret-stripe-secret-key");
+
+        classic.id = "env-api_key".to_string();
+        classic.file_path = None;
+        classic.line_number = None;
+        assert_eq!(rule_id(&classic), "env-api_key");
+    }
+}
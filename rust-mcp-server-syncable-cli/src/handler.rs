@@ -1,5 +1,7 @@
 // src/handler.rs
 
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use rust_mcp_sdk::schema::{
     schema_utils::CallToolError, CallToolRequest, CallToolResult, ListToolsRequest,
@@ -18,7 +20,7 @@ impl ServerHandler for MyServerHandler {
     async fn handle_list_tools_request(
         &self,
         _request: ListToolsRequest,
-        _runtime: &dyn McpServer,
+        _runtime: Arc<dyn McpServer>,
     ) -> std::result::Result<ListToolsResult, RpcError> {
         Ok(ListToolsResult {
             tools: ServerTools::tools(),
@@ -31,7 +33,7 @@ impl ServerHandler for MyServerHandler {
     async fn handle_call_tool_request(
         &self,
         request: CallToolRequest,
-        _runtime: &dyn McpServer,
+        _runtime: Arc<dyn McpServer>,
     ) -> std::result::Result<CallToolResult, CallToolError> {
         // Attempt to convert request parameters into the ServerTools enum
         let tool_call: ServerTools =
@@ -41,9 +43,11 @@ impl ServerHandler for MyServerHandler {
         match tool_call {
             ServerTools::AboutInfoTool(tool) => tool.call_tool(),
             ServerTools::AnalysisScanTool(tool) => tool.call_tool().await,
-            ServerTools::SecurityScanTool(tool) => tool.call_tool(),
+            ServerTools::SecurityScanTool(tool) => tool.call_tool().await,
             ServerTools::DependencyScanTool(tool) => tool.call_tool().await,
             ServerTools::VulnerabilityScanTool(tool) => tool.call_tool().await,
+            ServerTools::SbomTool(tool) => tool.call_tool().await,
+            ServerTools::ReportSchemaTool(tool) => tool.call_tool(),
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,96 @@
+// src/healthcheck.rs
+
+use std::time::Duration;
+
+use rust_mcp_sdk::schema::LATEST_PROTOCOL_VERSION;
+use serde_json::{json, Value};
+
+const HEALTHCHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Performs an MCP `initialize` round-trip against the local SSE server.
+///
+/// Returns `true` when the server answers with a valid `InitializeResult`, so the
+/// binary can be used as a container `HEALTHCHECK` without installing curl.
+pub async fn run_healthcheck(port: u16) -> bool {
+    match initialize_round_trip(port).await {
+        Ok(server_name) => {
+            eprintln!("✅ Healthy: {} responded on port {}", server_name, port);
+            true
+        }
+        Err(e) => {
+            eprintln!("❌ Unhealthy: {}", e);
+            false
+        }
+    }
+}
+
+async fn initialize_round_trip(port: u16) -> Result<String, String> {
+    let client = reqwest::Client::builder()
+        .timeout(HEALTHCHECK_TIMEOUT)
+        .build()
+        .map_err(|e| format!("Failed to build HTTP client: {}", e))?;
+    let endpoint = format!("http://127.0.0.1:{}/mcp", port);
+
+    let request = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "initialize",
+        "params": {
+            "protocolVersion": LATEST_PROTOCOL_VERSION,
+            "capabilities": {},
+            "clientInfo": { "name": "mcp-sse-healthcheck", "version": env!("CARGO_PKG_VERSION") }
+        }
+    });
+
+    let response = client
+        .post(&endpoint)
+        .header("accept", "application/json, text/event-stream")
+        .json(&request)
+        .send()
+        .await
+        .map_err(|e| format!("Request to {} failed: {}", endpoint, e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("{} returned HTTP {}", endpoint, response.status()));
+    }
+
+    let session_id = response
+        .headers()
+        .get("mcp-session-id")
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+    let body = response
+        .text()
+        .await
+        .map_err(|e| format!("Failed to read response body: {}", e))?;
+
+    // Release the session we just opened; a failure here doesn't affect health.
+    if let Some(session_id) = session_id {
+        let _ = client
+            .delete(&endpoint)
+            .header("mcp-session-id", session_id)
+            .send()
+            .await;
+    }
+
+    let message = parse_response_message(&body)
+        .ok_or_else(|| "Response did not contain a JSON-RPC message".to_string())?;
+    if let Some(error) = message.get("error") {
+        return Err(format!("Server returned an error: {}", error));
+    }
+    message
+        .pointer("/result/serverInfo/name")
+        .and_then(Value::as_str)
+        .map(str::to_string)
+        .ok_or_else(|| "Response is missing serverInfo".to_string())
+}
+
+/// Extracts the JSON-RPC message from either a plain JSON body or an SSE stream.
+fn parse_response_message(body: &str) -> Option<Value> {
+    if let Ok(value) = serde_json::from_str::<Value>(body) {
+        return Some(value);
+    }
+    body.lines()
+        .filter_map(|line| line.strip_prefix("data:"))
+        .find_map(|data| serde_json::from_str::<Value>(data.trim()).ok())
+}
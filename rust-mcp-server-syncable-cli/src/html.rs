@@ -0,0 +1,296 @@
+// src/html.rs
+
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::path::Path;
+
+use syncable_cli::analyzer::security::turbo::results::SecurityReport;
+
+use crate::changed_files::DiffScope;
+use crate::policy::PolicyDecision;
+use crate::rule_overrides::{self, OverrideSummary};
+use crate::security_baseline::BaselineSummary;
+use crate::security_merge::MergeSummary;
+use crate::severity::{SeverityRank, SEVERITIES};
+use crate::{cwe, finding, owasp};
+
+const STYLE: &str = "
+body { font-family: -apple-system, BlinkMacSystemFont, 'Segoe UI', sans-serif; margin: 2rem; color: #1f2328; }
+h1 { margin-bottom: 0.25rem; }
+.meta { color: #59636e; margin-bottom: 1.5rem; }
+.cards { display: flex; gap: 1rem; flex-wrap: wrap; margin-bottom: 1.5rem; }
+.card { border: 1px solid #d1d9e0; border-radius: 6px; padding: 0.75rem 1rem; min-width: 7rem; }
+.card b { display: block; font-size: 1.5rem; }
+.filters { display: flex; gap: 0.75rem; margin-bottom: 1rem; }
+table { border-collapse: collapse; width: 100%; }
+th, td { border-bottom: 1px solid #d1d9e0; padding: 0.5rem; text-align: left; vertical-align: top; }
+code { background: #f6f8fa; padding: 0 0.25rem; word-break: break-all; }
+.sev { font-weight: 600; text-transform: uppercase; font-size: 0.8rem; }
+.policy { border-radius: 6px; padding: 0.75rem 1rem; margin-bottom: 1.5rem; font-weight: 600; }
+.policy.passed { background: #dafbe1; color: #116329; } .policy.failed { background: #ffebe9; color: #a40e26; }
+.summary { display: grid; grid-template-columns: max-content 1fr; gap: 0.25rem 1rem; margin: 0 0 1.5rem; }
+.summary dt { font-weight: 600; } .summary dd { margin: 0; }
+.critical { color: #a40e26; } .high { color: #bc4c00; } .medium { color: #9a6700; } .low { color: #0969da; } .info { color: #59636e; }
+";
+
+const SCRIPT: &str = "
+const rows = document.querySelectorAll('tbody tr');
+const filters = ['severity', 'category', 'file'].map(id => document.getElementById(id));
+function apply() {
+  let shown = 0;
+  rows.forEach(row => {
+    const visible = filters.every(f => !f.value || (f.id === 'file'
+      ? row.dataset.file.toLowerCase().includes(f.value.toLowerCase())
+      : row.dataset[f.id] === f.value));
+    row.hidden = !visible;
+    if (visible) shown++;
+  });
+  document.getElementById('shown').textContent = shown;
+}
+filters.forEach(f => f.addEventListener('input', apply));
+";
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+fn options(values: impl IntoIterator<Item = String>) -> String {
+    values
+        .into_iter()
+        .map(|value| format!("<option value=\"{0}\">{0}</option>", escape(&value)))
+        .collect()
+}
+
+/// What `security_scan` did besides scanning, shown above the findings.
+pub struct ScanContext<'a> {
+    pub policy: &'a PolicyDecision,
+    pub diff: Option<&'a DiffScope>,
+    pub merge: Option<&'a MergeSummary>,
+    pub overrides: Option<&'a OverrideSummary>,
+    pub baseline: Option<&'a BaselineSummary>,
+}
+
+/// The policy banner and a definition list of the scan scope, suppressions and OWASP breakdown.
+fn summary(report: &SecurityReport, context: &ScanContext) -> String {
+    let policy = context.policy;
+    let mut html = format!(
+        "<div class=\"policy {status}\">Policy {status}: {count} blocking finding(s) at {fail_on} or above",
+        status = if policy.passed { "passed" } else { "failed" },
+        count = policy.blocking_findings,
+        fail_on = escape(&policy.fail_on),
+    );
+    if !policy.non_blocking_categories.is_empty() {
+        let _ = write!(html, " (non-blocking: {})", escape(&policy.non_blocking_categories.join(", ")));
+    }
+    html.push_str("</div>\n<dl class=\"summary\">");
+
+    if let Some(diff) = context.diff {
+        let _ = write!(
+            html,
+            "<dt>Changed files</dt><dd>{} file(s) changed since <code>{}</code></dd>",
+            diff.changed_files.len(),
+            escape(&diff.base)
+        );
+    }
+    if let Some(merge) = context.merge {
+        let _ = write!(
+            html,
+            "<dt>Engines</dt><dd>{} turbo and {} classic finding(s), {} duplicate(s) dropped</dd>",
+            merge.turbo_findings, merge.classic_findings, merge.duplicates
+        );
+    }
+    if let Some(overrides) = context.overrides {
+        let _ = write!(
+            html,
+            "<dt>Rule overrides</dt><dd>{} finding(s) re-graded, {} disabled by <code>{}</code></dd>",
+            overrides.overridden_findings,
+            overrides.disabled_findings,
+            rule_overrides::CONFIG_FILE
+        );
+    }
+    if let Some(baseline) = context.baseline {
+        let _ = write!(
+            html,
+            "<dt>Baseline</dt><dd>{} suppressed, {} new, {} resolved since {}{}</dd>",
+            baseline.suppressed_count,
+            baseline.new_count,
+            baseline.resolved_count,
+            baseline.created_at.to_rfc3339(),
+            if baseline.recorded { " (recorded by this scan)" } else { "" }
+        );
+    }
+    let owasp = owasp::counts(report)
+        .into_iter()
+        .map(|(category, count)| format!("{}: {}", escape(category), count))
+        .collect::<Vec<_>>();
+    if !owasp.is_empty() {
+        let _ = write!(html, "<dt>OWASP Top 10</dt><dd>{}</dd>", owasp.join("<br>"));
+    }
+    let cwes = cwe::counts(report)
+        .into_iter()
+        .map(|(id, cwe)| {
+            let label = match cwe.name {
+                Some(name) => format!("{} {}", id, name),
+                None => id,
+            };
+            format!("<a href=\"{}\">{}</a>: {}", escape(&cwe.url), escape(&label), cwe.count)
+        })
+        .collect::<Vec<_>>();
+    if !cwes.is_empty() {
+        let _ = write!(html, "<dt>CWE</dt><dd>{}</dd>", cwes.join("<br>"));
+    }
+    html.push_str("</dl>");
+    html
+}
+
+/// Renders a security report as a single HTML file with severity, category and file filters.
+pub fn security(report: &SecurityReport, project_path: &Path, context: &ScanContext) -> String {
+    let mut findings = report.findings.iter().collect::<Vec<_>>();
+    findings.sort_by_key(|finding| {
+        (finding.severity.rank(), finding.file_path.clone(), finding.line_number)
+    });
+
+    let categories: BTreeSet<String> = findings.iter().map(|f| format!("{:?}", f.category)).collect();
+    let mut rows = String::new();
+    for finding in &findings {
+        let severity = finding.severity.name();
+        let category = format!("{:?}", finding.category);
+        let file = finding::relative_file(finding, project_path).unwrap_or_default();
+        let location = match finding.line_number {
+            Some(line) if !file.is_empty() => format!("{}:{}", file, line),
+            _ => file.clone(),
+        };
+        let mut details = escape(&finding.description);
+        if let Some(evidence) = &finding.evidence {
+            let _ = write!(details, "<br><code>{}</code>", escape(evidence));
+        }
+        for step in &finding.remediation {
+            let _ = write!(details, "<br>&rarr; {}", escape(step));
+        }
+        let _ = writeln!(
+            rows,
+            "<tr data-severity=\"{severity}\" data-category=\"{category}\" data-file=\"{file}\">\
+             <td class=\"sev {severity}\">{severity}</td><td>{title}</td><td>{category}<br><small>{owasp}</small></td>\
+             <td><code>{location}</code></td><td>{details}</td></tr>",
+            severity = severity,
+            category = escape(&category),
+            file = escape(&file),
+            title = escape(&finding.title),
+            owasp = escape(owasp::top10(finding).unwrap_or("")),
+            location = escape(&location),
+            details = details,
+        );
+    }
+
+    let mut cards = format!(
+        "<div class=\"card\">Score<b>{:.0}</b></div><div class=\"card\">Risk<b class=\"{}\">{}</b></div>\
+         <div class=\"card\">Findings<b>{}</b></div><div class=\"card\">Files scanned<b>{}</b></div>",
+        report.overall_score,
+        report.risk_level.name(),
+        report.risk_level.name(),
+        report.total_findings,
+        report.files_scanned,
+    );
+    for severity in SEVERITIES {
+        let count = findings.iter().filter(|f| f.severity.name() == severity).count();
+        let _ = write!(cards, "<div class=\"card {0}\">{0}<b>{1}</b></div>", severity, count);
+    }
+
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>Security report: {project}</title>\n<style>{style}</style>\n</head>\n<body>\n\
+         <h1>Security report</h1>\n<div class=\"meta\">{project} &middot; analyzed {analyzed_at}</div>\n\
+         {summary}\n<div class=\"cards\">{cards}</div>\n\
+         <div class=\"filters\">\
+         <select id=\"severity\"><option value=\"\">All severities</option>{severities}</select>\
+         <select id=\"category\"><option value=\"\">All categories</option>{categories}</select>\
+         <input id=\"file\" placeholder=\"Filter by file\">\
+         <span>Showing <span id=\"shown\">{total}</span> of {total}</span></div>\n\
+         <table>\n<thead><tr><th>Severity</th><th>Finding</th><th>Category</th><th>Location</th><th>Details</th></tr></thead>\n\
+         <tbody>\n{rows}</tbody>\n</table>\n<script>{script}</script>\n</body>\n</html>\n",
+        project = escape(&project_path.to_string_lossy()),
+        style = STYLE,
+        analyzed_at = report.analyzed_at.to_rfc3339(),
+        summary = summary(report, context),
+        cards = cards,
+        severities = options(SEVERITIES.map(String::from)),
+        categories = options(categories),
+        total = findings.len(),
+        rows = rows,
+        script = SCRIPT,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use syncable_cli::analyzer::security::SecuritySeverity;
+
+    use super::*;
+    use crate::test_support::{project, report, secret};
+
+    #[test]
+    fn renders_escaped_rows_most_severe_first() {
+        let mut low = secret("long-secret-value", "src/app.js", 7, SecuritySeverity::Low);
+        low.title = "<script>alert(1)</script>".to_string();
+        let report = report(vec![low, secret("aws-access-key", "config.js", 1, SecuritySeverity::Critical)]);
+
+        let policy = PolicyDecision {
+            fail_on: "high".to_string(),
+            non_blocking_categories: vec![],
+            blocking_findings: 1,
+            passed: false,
+        };
+        let context = ScanContext { policy: &policy, diff: None, merge: None, overrides: None, baseline: None };
+
+        let html = security(&report, project(), &context);
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<code>config.js:1</code>"));
+        assert!(html.contains("&lt;script&gt;alert(1)&lt;/script&gt;"));
+        assert!(!html.contains("<script>alert(1)"));
+        let critical = html.find("data-severity=\"critical\"").unwrap();
+        let low = html.find("data-severity=\"low\"").unwrap();
+        assert!(critical < low);
+        assert!(html.contains("Policy failed: 1 blocking finding(s) at high or above"));
+        assert!(html.contains("A07:2021-Identification and Authentication Failures: 2"));
+        assert!(!html.contains("<dt>Baseline</dt>"));
+    }
+
+    #[test]
+    fn summarises_scope_and_suppressions() {
+        let report = report(vec![]);
+        let policy = PolicyDecision {
+            fail_on: "medium".to_string(),
+            non_blocking_categories: vec!["CodeSecurityPattern".to_string()],
+            blocking_findings: 0,
+            passed: true,
+        };
+        let diff = DiffScope { base: "origin/main".to_string(), changed_files: vec!["src/app.js".to_string()] };
+        let merge = MergeSummary { turbo_findings: 3, classic_findings: 2, duplicates: 1 };
+        let overrides = OverrideSummary { overridden_findings: 1, disabled_findings: 2 };
+        let baseline = BaselineSummary {
+            created_at: report.analyzed_at,
+            recorded: false,
+            suppressed_count: 4,
+            new_count: 0,
+            resolved_count: 1,
+        };
+        let context = ScanContext {
+            policy: &policy,
+            diff: Some(&diff),
+            merge: Some(&merge),
+            overrides: Some(&overrides),
+            baseline: Some(&baseline),
+        };
+
+        let html = security(&report, project(), &context);
+        assert!(html.contains("Policy passed: 0 blocking finding(s) at medium or above (non-blocking: CodeSecurityPattern)"));
+        assert!(html.contains("1 file(s) changed since <code>origin/main</code>"));
+        assert!(html.contains("3 turbo and 2 classic finding(s), 1 duplicate(s) dropped"));
+        assert!(html.contains("1 finding(s) re-graded, 2 disabled"));
+        assert!(html.contains("4 suppressed, 0 new, 1 resolved"));
+    }
+}
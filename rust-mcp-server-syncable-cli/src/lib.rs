@@ -1,16 +1,45 @@
+mod changed_files;
+mod cwe;
+mod dependency_scope;
+mod finding;
 mod handler;
+mod healthcheck;
+mod html;
+mod listen_fd;
+mod owasp;
+mod policy;
+mod project_file;
+mod report;
+mod rule_overrides;
+mod sarif;
+mod sbom;
+mod security_baseline;
+mod security_merge;
+mod severity;
+#[cfg(test)]
+mod test_support;
+// `tool_box!` names every variant after its tool struct, so they all end in `Tool`.
+#[allow(clippy::enum_variant_names)]
 mod tools;
+mod vuln_baseline;
+mod vuln_ignore;
 
 use handler::MyServerHandler;
 use rust_mcp_sdk::{
     error::SdkResult,
-    mcp_server::{hyper_server, server_runtime, HyperServerOptions, ServerRuntime},
+    mcp_server::{hyper_server, server_runtime, HyperServerOptions},
     schema::{
         Implementation, InitializeResult, ServerCapabilities, ServerCapabilitiesTools,
         LATEST_PROTOCOL_VERSION,
     },
+    event_store::InMemoryEventStore,
     McpServer, StdioTransport, TransportOptions,
 };
+use std::{
+    net::Ipv4Addr,
+    sync::Arc,
+    time::Duration,
+};
 use tools::ServerTools;
 use tracing_subscriber::{fmt, prelude::*, EnvFilter};
 
@@ -55,14 +84,25 @@ pub async fn start_stdio() -> SdkResult<()> {
     // 4) Create transport and runtime
     let transport = StdioTransport::new(TransportOptions::default())?;
     let handler = MyServerHandler {};
-    let server: ServerRuntime = server_runtime::create_server(server_details, transport, handler);
+    let server = server_runtime::create_server(server_details, transport, handler);
 
     // 5) Run
     server.start().await?;
     Ok(())
 }
 
+pub use listen_fd::SocketActivation;
+pub use report::SCHEMA_VERSION;
+
+/// Runs the SSE server on `MCP_PORT`, without socket activation.
 pub async fn start_sse() -> SdkResult<()> {
+    start_sse_with_activation(SocketActivation::default()).await
+}
+
+/// Runs the SSE server, accepting connections on the activated socket if there is one.
+///
+/// Read `activation` with [`SocketActivation::from_env`] before the runtime starts.
+pub async fn start_sse_with_activation(activation: SocketActivation) -> SdkResult<()> {
     // 1) Initialize tracing
     tracing_subscriber::registry()
         .with(fmt::layer())
@@ -104,21 +144,69 @@ pub async fn start_sse() -> SdkResult<()> {
 
     // 4) Create handler & server options
     let handler = MyServerHandler {};
-    let port = std::env::var("MCP_PORT")
+    // Under socket activation the server listens on an ephemeral loopback port and the
+    // inherited socket, which usually holds MCP_PORT itself, relays to it
+    let activated_listener = activation.into_listener();
+    let (host, port) = if activated_listener.is_some() {
+        (Ipv4Addr::LOCALHOST, 0)
+    } else {
+        (Ipv4Addr::UNSPECIFIED, sse_port())
+    };
+    // Keep idle streams alive behind load balancers that drop silent connections
+    let ping_interval = std::env::var("MCP_PING_INTERVAL_SECS")
         .ok()
-        .and_then(|s| s.parse::<u16>().ok())
-        .unwrap_or(8008);
+        .and_then(|s| s.parse::<u64>().ok())
+        .filter(|secs| *secs > 0)
+        .map(Duration::from_secs)
+        .unwrap_or(Duration::from_secs(15));
+    // Buffered events get IDs so clients can resume with `Last-Event-ID` after a reconnect
+    // The store only evicts once a session reaches the limit, so 0 would never evict
+    let event_store_size = std::env::var("MCP_EVENT_STORE_SIZE")
+        .ok()
+        .and_then(|s| s.parse::<usize>().ok())
+        .filter(|n| *n > 0);
     let options = HyperServerOptions {
-        host: "0.0.0.0".to_string(),
+        host: host.to_string(),
         port,
+        ping_interval,
+        event_store: Some(Arc::new(InMemoryEventStore::new(event_store_size))),
         ..Default::default()
     };
 
     tracing::info!("Creating the MCP SSE server...");
     let server = hyper_server::create_server(server_details, handler, options);
 
-    tracing::info!("✅ SSE server listening on http://0.0.0.0:{}", port);
+    if let Some(listener) = activated_listener {
+        // Relay to wherever the server ended up, once it is bound
+        let handle = server.server_handle();
+        tokio::spawn(async move {
+            match handle.listening().await {
+                Some(upstream) => listen_fd::relay_connections(listener, upstream).await,
+                None => tracing::error!("MCP server stopped before it started listening"),
+            }
+        });
+    } else {
+        tracing::info!("✅ SSE server listening on http://{}:{}", host, port);
+    }
+    tracing::info!(
+        "💓 Keep-alive ping every {}s, resumable streams enabled",
+        ping_interval.as_secs()
+    );
     // 5) Run
     server.start().await?;
     Ok(())
 }
+
+/// Runs an MCP initialize round-trip against the local SSE server.
+///
+/// Returns `true` if the server is healthy.
+pub async fn healthcheck() -> bool {
+    healthcheck::run_healthcheck(sse_port()).await
+}
+
+fn sse_port() -> u16 {
+    std::env::var("MCP_PORT")
+        .ok()
+        .and_then(|s| s.parse::<u16>().ok())
+        .unwrap_or(8008)
+}
@@ -0,0 +1,176 @@
+// src/listen_fd.rs
+
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use tokio::net::{TcpListener, TcpStream};
+
+/// Shortest and longest pause before retrying after `accept` fails.
+const ACCEPT_BACKOFF_MIN: Duration = Duration::from_millis(10);
+const ACCEPT_BACKOFF_MAX: Duration = Duration::from_secs(1);
+
+/// First file descriptor passed by systemd-style socket activation.
+#[cfg(unix)]
+const SD_LISTEN_FDS_START: i32 = 3;
+
+/// Number of descriptors passed to this process, given the `LISTEN_FDS` and `LISTEN_PID` values.
+#[cfg(unix)]
+fn activated_fds(listen_fds: Option<&str>, listen_pid: Option<&str>, pid: u32) -> Option<i32> {
+    let fds = listen_fds?.parse::<i32>().ok().filter(|fds| *fds > 0)?;
+    // LISTEN_PID guards against inheriting descriptors meant for a parent process
+    match listen_pid.map(str::parse::<u32>) {
+        Some(Ok(listen_pid)) if listen_pid != pid => None,
+        Some(Err(_)) => None,
+        _ => Some(fds),
+    }
+}
+
+/// Whether `fd` is a TCP stream socket that is already listening.
+#[cfg(unix)]
+fn is_listening_stream(fd: std::os::fd::RawFd) -> bool {
+    let option = |name| {
+        let mut value: libc::c_int = 0;
+        let mut len = std::mem::size_of::<libc::c_int>() as libc::socklen_t;
+        // SAFETY: `value` and `len` describe a c_int-sized buffer, as both options expect.
+        let rc = unsafe {
+            libc::getsockopt(fd, libc::SOL_SOCKET, name, (&mut value as *mut libc::c_int).cast(), &mut len)
+        };
+        (rc == 0).then_some(value)
+    };
+    option(libc::SO_TYPE) == Some(libc::SOCK_STREAM) && option(libc::SO_ACCEPTCONN) == Some(1)
+}
+
+/// Sockets passed in by systemd-style socket activation (`LISTEN_FDS`/`LISTEN_PID`).
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SocketActivation {
+    fds: Option<i32>,
+}
+
+impl SocketActivation {
+    /// Reads the activation variables and unsets them, so child processes such as `git`
+    /// don't mistake the descriptors for their own.
+    ///
+    /// Changing the environment is unsound while another thread may read it, so call this
+    /// before starting the async runtime.
+    pub fn from_env() -> Self {
+        #[cfg(unix)]
+        {
+            let listen_fds = std::env::var("LISTEN_FDS").ok();
+            let listen_pid = std::env::var("LISTEN_PID").ok();
+            for name in ["LISTEN_FDS", "LISTEN_PID", "LISTEN_FDNAMES"] {
+                std::env::remove_var(name);
+            }
+            Self { fds: activated_fds(listen_fds.as_deref(), listen_pid.as_deref(), std::process::id()) }
+        }
+        #[cfg(not(unix))]
+        Self::default()
+    }
+
+    /// Takes ownership of the activated listener, if there is a usable one.
+    #[cfg(unix)]
+    pub(crate) fn into_listener(self) -> Option<std::net::TcpListener> {
+        use std::os::fd::FromRawFd;
+
+        let fds = self.fds?;
+        if fds > 1 {
+            tracing::warn!("Received {} activated sockets, only the first is used", fds);
+        }
+        if !is_listening_stream(SD_LISTEN_FDS_START) {
+            tracing::error!("Activated socket is not a listening TCP stream socket");
+            return None;
+        }
+
+        // SAFETY: the service manager hands us ownership of this descriptor, and it was
+        // checked above to be a listening stream socket.
+        let listener = unsafe { std::net::TcpListener::from_raw_fd(SD_LISTEN_FDS_START) };
+        if let Err(e) = listener.local_addr().and_then(|_| listener.set_nonblocking(true)) {
+            tracing::error!("Activated socket is not a usable TCP listener: {}", e);
+            return None;
+        }
+        Some(listener)
+    }
+
+    #[cfg(not(unix))]
+    pub(crate) fn into_listener(self) -> Option<std::net::TcpListener> {
+        None
+    }
+}
+
+/// Relays every connection accepted on the activated socket to the MCP server.
+///
+/// The SDK binds its own listener, so the server runs on loopback and this
+/// forwards traffic from the inherited socket to it.
+pub async fn relay_connections(listener: std::net::TcpListener, upstream: SocketAddr) {
+    let listener = match TcpListener::from_std(listener) {
+        Ok(listener) => listener,
+        Err(e) => {
+            tracing::error!("Failed to adopt activated socket: {}", e);
+            return;
+        }
+    };
+    if let Ok(addr) = listener.local_addr() {
+        tracing::info!("🔌 Accepting activated connections on {}", addr);
+    }
+
+    let mut backoff = ACCEPT_BACKOFF_MIN;
+    loop {
+        let (mut inbound, peer) = match listener.accept().await {
+            Ok(conn) => {
+                backoff = ACCEPT_BACKOFF_MIN;
+                conn
+            }
+            Err(e) => {
+                // Errors such as EMFILE last until connections close, so retrying at once would spin
+                tracing::warn!("Failed to accept connection, retrying in {:?}: {}", backoff, e);
+                tokio::time::sleep(backoff).await;
+                backoff = (backoff * 2).min(ACCEPT_BACKOFF_MAX);
+                continue;
+            }
+        };
+        tokio::spawn(async move {
+            match TcpStream::connect(upstream).await {
+                Ok(mut outbound) => {
+                    if let Err(e) = tokio::io::copy_bidirectional(&mut inbound, &mut outbound).await
+                    {
+                        tracing::debug!("Connection from {} closed: {}", peer, e);
+                    }
+                }
+                Err(e) => tracing::warn!("Failed to reach MCP server at {}: {}", upstream, e),
+            }
+        });
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use std::os::fd::AsRawFd;
+
+    use super::*;
+
+    #[test]
+    fn parses_listen_env() {
+        assert_eq!(activated_fds(Some("1"), Some("42"), 42), Some(1));
+        assert_eq!(activated_fds(Some("2"), None, 42), Some(2));
+        assert_eq!(activated_fds(None, Some("42"), 42), None);
+        assert_eq!(activated_fds(Some("0"), Some("42"), 42), None);
+        assert_eq!(activated_fds(Some("-1"), Some("42"), 42), None);
+        assert_eq!(activated_fds(Some("one"), Some("42"), 42), None);
+        assert_eq!(activated_fds(Some("1"), Some("41"), 42), None, "meant for another process");
+        assert_eq!(activated_fds(Some("1"), Some("parent"), 42), None);
+    }
+
+    #[test]
+    fn accepts_only_listening_stream_sockets() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        assert!(is_listening_stream(listener.as_raw_fd()));
+
+        let stream = std::net::TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        assert!(!is_listening_stream(stream.as_raw_fd()), "connected, not listening");
+
+        let udp = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        assert!(!is_listening_stream(udp.as_raw_fd()), "datagram socket");
+
+        let file = tempfile::tempfile().unwrap();
+        assert!(!is_listening_stream(file.as_raw_fd()), "not a socket");
+    }
+}
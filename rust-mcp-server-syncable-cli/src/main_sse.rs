@@ -1,8 +1,19 @@
 // src/main_sse.rs
-use rust_mcp_server_syncable_cli::start_sse;
+use rust_mcp_server_syncable_cli::{healthcheck, start_sse_with_activation, SocketActivation};
 
-#[tokio::main]
-async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    start_sse().await?;
-    Ok(())
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Read and clear LISTEN_* while this is the only thread; the runtime's workers may read the environment
+    let activation = SocketActivation::from_env();
+    let runtime = tokio::runtime::Runtime::new()?;
+
+    runtime.block_on(async {
+        // `mcp-sse healthcheck` probes a running server and exits 0 (healthy) or 1
+        if std::env::args().nth(1).as_deref() == Some("healthcheck") {
+            let healthy = healthcheck().await;
+            std::process::exit(if healthy { 0 } else { 1 });
+        }
+
+        start_sse_with_activation(activation).await?;
+        Ok(())
+    })
 }
@@ -0,0 +1,136 @@
+// src/owasp.rs
+
+use std::collections::BTreeMap;
+
+use serde_json::{json, Value};
+use syncable_cli::analyzer::security::turbo::results::SecurityReport;
+use syncable_cli::analyzer::security::{SecurityCategory, SecurityFinding};
+
+use crate::cwe;
+
+const A01: &str = "A01:2021-Broken Access Control";
+const A02: &str = "A02:2021-Cryptographic Failures";
+const A03: &str = "A03:2021-Injection";
+const A04: &str = "A04:2021-Insecure Design";
+const A05: &str = "A05:2021-Security Misconfiguration";
+/// Every dependency advisory falls under vulnerable and outdated components.
+pub const A06: &str = "A06:2021-Vulnerable and Outdated Components";
+const A07: &str = "A07:2021-Identification and Authentication Failures";
+const A08: &str = "A08:2021-Software and Data Integrity Failures";
+const A09: &str = "A09:2021-Security Logging and Monitoring Failures";
+const A10: &str = "A10:2021-Server-Side Request Forgery";
+
+/// CWEs mapped to an OWASP Top 10 (2021) category, following the OWASP mapping lists.
+const CWE_CATEGORIES: &[(&str, &[u32])] = &[
+    (A01, &[22, 200, 201, 284, 285, 352, 359, 425, 639, 862, 863]),
+    (A02, &[259, 261, 296, 310, 319, 321, 326, 327, 328, 330, 331, 916]),
+    (A03, &[20, 74, 77, 78, 79, 89, 90, 91, 94, 95, 917, 943]),
+    (A04, &[209, 256, 501, 522]),
+    (A05, &[16, 260, 315, 520, 526, 611, 614, 756, 942, 1004]),
+    (A07, &[255, 287, 288, 290, 294, 295, 297, 306, 307, 384, 521, 613, 620, 640, 798]),
+    (A08, &[345, 353, 426, 494, 502, 565, 784, 829, 830, 915]),
+    (A09, &[117, 223, 532, 778]),
+    (A10, &[918]),
+];
+
+/// The OWASP Top 10 (2021) category a CWE is mapped to.
+pub fn cwe_category(cwe: u32) -> Option<&'static str> {
+    CWE_CATEGORIES
+        .iter()
+        .find(|(_, cwes)| cwes.contains(&cwe))
+        .map(|(category, _)| *category)
+}
+
+/// Every CWE with an OWASP Top 10 category.
+#[cfg(test)]
+pub fn mapped_cwes() -> impl Iterator<Item = u32> {
+    CWE_CATEGORIES.iter().flat_map(|(_, cwes)| cwes.iter().copied())
+}
+
+/// The OWASP Top 10 (2021) category of a finding, from its CWE or else its security category.
+pub fn top10(finding: &SecurityFinding) -> Option<&'static str> {
+    if let Some(category) = finding.cwe_id.as_deref().and_then(cwe::number).and_then(cwe_category) {
+        return Some(category);
+    }
+    match finding.category {
+        SecurityCategory::SecretsExposure | SecurityCategory::AuthenticationSecurity => Some(A07),
+        SecurityCategory::InsecureConfiguration | SecurityCategory::InfrastructureSecurity => Some(A05),
+        SecurityCategory::DataProtection | SecurityCategory::NetworkSecurity => Some(A02),
+        SecurityCategory::CodeInjection | SecurityCategory::CommandInjection => Some(A03),
+        SecurityCategory::CodeSecurityPattern | SecurityCategory::Compliance => None,
+    }
+}
+
+/// Tags each serialized finding with `owasp_top10` and adds the `findings_by_owasp_top10` and
+/// `findings_by_cwe` breakdowns.
+///
+/// `output` must be the serialization of `report`, so findings line up by index.
+pub fn annotate(output: &mut Value, report: &SecurityReport) {
+    for (index, finding) in report.findings.iter().enumerate() {
+        output["findings"][index]["owasp_top10"] = json!(top10(finding));
+    }
+    output["findings_by_owasp_top10"] = json!(counts(report));
+    output["findings_by_cwe"] = json!(cwe::counts(report));
+}
+
+/// Number of findings in each OWASP Top 10 category; unmapped findings are left out.
+pub fn counts(report: &SecurityReport) -> BTreeMap<&'static str, usize> {
+    let mut counts = BTreeMap::new();
+    for category in report.findings.iter().filter_map(top10) {
+        *counts.entry(category).or_default() += 1;
+    }
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use syncable_cli::analyzer::security::SecuritySeverity;
+
+    use super::*;
+    use crate::test_support::{finding, report};
+
+    fn with_cwe(cwe: Option<&str>, category: SecurityCategory) -> SecurityFinding {
+        let rule = format!("{:?}", category).to_lowercase();
+        let mut finding = finding(&rule, "app.js", 1, SecuritySeverity::High, category);
+        finding.cwe_id = cwe.map(str::to_string);
+        finding
+    }
+
+    #[test]
+    fn maps_cwe_before_category() {
+        assert_eq!(top10(&with_cwe(Some("CWE-89"), SecurityCategory::SecretsExposure)), Some(A03));
+        assert_eq!(top10(&with_cwe(Some(" cwe-918 "), SecurityCategory::Compliance)), Some(A10));
+        assert_eq!(top10(&with_cwe(Some("CWE-798"), SecurityCategory::CodeSecurityPattern)), Some(A07));
+    }
+
+    #[test]
+    fn falls_back_to_category_for_unmapped_or_missing_cwes() {
+        assert_eq!(top10(&with_cwe(None, SecurityCategory::SecretsExposure)), Some(A07));
+        assert_eq!(top10(&with_cwe(Some("CWE-1"), SecurityCategory::InsecureConfiguration)), Some(A05));
+        assert_eq!(top10(&with_cwe(Some("not-a-cwe"), SecurityCategory::CommandInjection)), Some(A03));
+        assert_eq!(top10(&with_cwe(None, SecurityCategory::NetworkSecurity)), Some(A02));
+        assert_eq!(top10(&with_cwe(None, SecurityCategory::CodeSecurityPattern)), None);
+    }
+
+    #[test]
+    fn annotate_tags_findings_by_index() {
+        // The aggregator sorts by severity, so the serialized order is the report's order.
+        let mut low = with_cwe(Some("CWE-89"), SecurityCategory::CodeInjection);
+        low.severity = SecuritySeverity::Low;
+        let report = report(vec![
+            low,
+            with_cwe(None, SecurityCategory::CodeSecurityPattern),
+            with_cwe(Some("CWE-798"), SecurityCategory::SecretsExposure),
+        ]);
+        let mut output = serde_json::to_value(&report).unwrap();
+
+        annotate(&mut output, &report);
+        for (index, finding) in report.findings.iter().enumerate() {
+            assert_eq!(output["findings"][index]["id"], json!(finding.id));
+            assert_eq!(output["findings"][index]["owasp_top10"], json!(top10(finding)));
+        }
+        assert_eq!(output["findings"][2]["owasp_top10"], json!(A03));
+        assert_eq!(output["findings_by_owasp_top10"], json!({ A03: 1, A07: 1 }));
+        assert_eq!(output["findings_by_cwe"]["CWE-89"]["count"], 1);
+    }
+}
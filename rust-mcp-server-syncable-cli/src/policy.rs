@@ -0,0 +1,131 @@
+// src/policy.rs
+
+use serde::Serialize;
+
+use crate::severity::{parse_severity, SeverityRank, SEVERITIES};
+
+/// Decides whether a report's findings should fail a build.
+///
+/// The default mirrors `sync-ctl`, which exits non-zero on high or critical findings.
+#[derive(Debug)]
+pub struct SeverityPolicy {
+    /// Rank of the lowest failing severity; `None` never fails.
+    fail_on: Option<usize>,
+    non_blocking_categories: Vec<String>,
+}
+
+/// The policy outcome returned alongside a report.
+#[derive(Debug, Serialize)]
+pub struct PolicyDecision {
+    pub fail_on: String,
+    pub non_blocking_categories: Vec<String>,
+    pub blocking_findings: usize,
+    pub passed: bool,
+}
+
+impl SeverityPolicy {
+    pub fn from_params(
+        fail_on: Option<&str>,
+        non_blocking_categories: Option<&[String]>,
+    ) -> Result<Self, String> {
+        let fail_on = match fail_on.unwrap_or("high") {
+            level if level.eq_ignore_ascii_case("none") => None,
+            level => Some(parse_severity(level).ok_or_else(|| {
+                format!(
+                    "Unknown fail_on severity '{}': expected critical, high, medium, low, info or none",
+                    level
+                )
+            })?),
+        };
+        Ok(Self {
+            fail_on,
+            non_blocking_categories: non_blocking_categories.unwrap_or_default().to_vec(),
+        })
+    }
+
+    /// Evaluates `(severity, category)` pairs. Categories are vulnerability types such as
+    /// `security`, `unmaintained` or `yanked`, or security category names such as `SecretsExposure`.
+    pub fn evaluate<'a, S: SeverityRank + 'a, C: AsRef<str>>(
+        &self,
+        findings: impl Iterator<Item = (&'a S, C)>,
+    ) -> PolicyDecision {
+        let blocking_findings = match self.fail_on {
+            Some(threshold) => findings
+                .filter(|(severity, category)| {
+                    severity.rank() <= threshold
+                        && !self
+                            .non_blocking_categories
+                            .iter()
+                            .any(|c| c.eq_ignore_ascii_case(category.as_ref()))
+                })
+                .count(),
+            None => 0,
+        };
+        PolicyDecision {
+            fail_on: self.fail_on.map_or("none", |i| SEVERITIES[i]).to_string(),
+            non_blocking_categories: self.non_blocking_categories.clone(),
+            blocking_findings,
+            passed: blocking_findings == 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syncable_cli::analyzer::security::SecuritySeverity;
+    use syncable_cli::analyzer::vulnerability::VulnerabilitySeverity;
+
+    use super::*;
+
+    fn policy(fail_on: Option<&str>, non_blocking: &[&str]) -> SeverityPolicy {
+        let categories: Vec<String> = non_blocking.iter().map(|c| c.to_string()).collect();
+        SeverityPolicy::from_params(fail_on, Some(&categories)).unwrap()
+    }
+
+    const FINDINGS: [(SecuritySeverity, &str); 4] = [
+        (SecuritySeverity::Critical, "SecretsExposure"),
+        (SecuritySeverity::High, "CodeSecurityPattern"),
+        (SecuritySeverity::Medium, "SecretsExposure"),
+        (SecuritySeverity::Info, "SecretsExposure"),
+    ];
+
+    fn blocking(policy: &SeverityPolicy) -> usize {
+        policy.evaluate(FINDINGS.iter().map(|(severity, category)| (severity, *category))).blocking_findings
+    }
+
+    #[test]
+    fn fail_on_is_an_inclusive_threshold() {
+        assert_eq!(blocking(&policy(None, &[])), 2, "defaults to high");
+        assert_eq!(blocking(&policy(Some("critical"), &[])), 1);
+        assert_eq!(blocking(&policy(Some("Medium"), &[])), 3);
+        assert_eq!(blocking(&policy(Some("info"), &[])), 4);
+        assert_eq!(blocking(&policy(Some("none"), &[])), 0);
+    }
+
+    #[test]
+    fn non_blocking_categories_are_case_insensitive() {
+        let decision = policy(Some("info"), &["codesecuritypattern"])
+            .evaluate(FINDINGS.iter().map(|(severity, category)| (severity, *category)));
+        assert_eq!(decision.blocking_findings, 3);
+        assert!(!decision.passed);
+        assert_eq!(decision.fail_on, "info");
+        assert_eq!(decision.non_blocking_categories, ["codesecuritypattern"]);
+    }
+
+    #[test]
+    fn evaluates_vulnerability_severities_on_the_same_scale() {
+        let findings = [(VulnerabilitySeverity::Medium, "security"), (VulnerabilitySeverity::Low, "unmaintained")];
+        let decision = policy(Some("medium"), &[]).evaluate(findings.iter().map(|(s, c)| (s, *c)));
+        assert_eq!(decision.blocking_findings, 1);
+
+        let decision = policy(None, &[]).evaluate(findings.iter().map(|(s, c)| (s, *c)));
+        assert!(decision.passed);
+        assert_eq!(decision.fail_on, "high");
+    }
+
+    #[test]
+    fn rejects_unknown_thresholds() {
+        let error = SeverityPolicy::from_params(Some("severe"), None).unwrap_err();
+        assert!(error.contains("Unknown fail_on severity 'severe'"), "{}", error);
+    }
+}
@@ -0,0 +1,68 @@
+// src/project_file.rs
+
+use std::path::{Component, Path, PathBuf};
+
+/// Resolves a file argument such as `baseline` or `snapshot` against the project.
+///
+/// The tools read and write these files on behalf of the client, so a path that is
+/// absolute or leaves the project, through `..` or a symlink, is rejected.
+pub fn resolve(project_path: &Path, param: &str, file: &str) -> Result<PathBuf, String> {
+    let invalid = || format!("Invalid {} '{}': expected a path inside the project, relative to it", param, file);
+    let relative = Path::new(file);
+    let is_relative = relative
+        .components()
+        .all(|component| matches!(component, Component::Normal(_) | Component::CurDir));
+    if file.is_empty() || !is_relative {
+        return Err(invalid());
+    }
+
+    let path = project_path.join(relative);
+    // A missing project fails the scan itself with a clearer error.
+    let Ok(root) = project_path.canonicalize() else {
+        return Ok(path);
+    };
+    // The deepest existing part of the path must resolve inside the project; a dangling
+    // symlink does not resolve at all, so it is rejected too.
+    let existing = path
+        .ancestors()
+        .find(|ancestor| ancestor.symlink_metadata().is_ok())
+        .and_then(|ancestor| ancestor.canonicalize().ok());
+    if !existing.is_some_and(|resolved| resolved.starts_with(&root)) {
+        return Err(invalid());
+    }
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_paths_inside_the_project() {
+        let project = tempfile::tempdir().unwrap();
+        let path = resolve(project.path(), "baseline", ".syncable/vuln-baseline.json").unwrap();
+        assert_eq!(path, project.path().join(".syncable/vuln-baseline.json"));
+        assert!(resolve(project.path(), "snapshot", "./reports/snapshot.json").is_ok());
+    }
+
+    #[test]
+    fn rejects_absolute_and_escaping_paths() {
+        let project = tempfile::tempdir().unwrap();
+        for file in ["", "/etc/cron.d/job", "../outside.json", "reports/../../outside.json"] {
+            let error = resolve(project.path(), "snapshot", file).unwrap_err();
+            assert!(error.starts_with(&format!("Invalid snapshot '{}'", file)), "{}", error);
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn rejects_paths_through_symlinks_out_of_the_project() {
+        let outside = tempfile::tempdir().unwrap();
+        let project = tempfile::tempdir().unwrap();
+        std::os::unix::fs::symlink(outside.path(), project.path().join("linked")).unwrap();
+        std::os::unix::fs::symlink(outside.path().join("missing.json"), project.path().join("dangling.json")).unwrap();
+
+        assert!(resolve(project.path(), "baseline", "linked/baseline.json").is_err());
+        assert!(resolve(project.path(), "baseline", "dangling.json").is_err());
+    }
+}
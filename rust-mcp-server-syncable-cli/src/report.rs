@@ -0,0 +1,39 @@
+// src/report.rs
+
+use serde_json::{json, Value};
+
+/// Version of the JSON reports returned by `vulnerability_scan` and `security_scan`.
+///
+/// Bump the major part when a field is removed, renamed or retyped; additions bump the minor part.
+pub const SCHEMA_VERSION: &str = "1.0";
+
+pub const VULNERABILITY_REPORT_SCHEMA: &str =
+    include_str!("../schemas/vulnerability-report.schema.json");
+pub const SECURITY_REPORT_SCHEMA: &str = include_str!("../schemas/security-report.schema.json");
+
+/// Stamps a serialized report with the schema version it conforms to.
+pub fn versioned(mut report: Value) -> Value {
+    if let Some(object) = report.as_object_mut() {
+        object.insert("schema_version".to_string(), json!(SCHEMA_VERSION));
+    }
+    report
+}
+
+/// Output formats accepted by the scan tools.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Json,
+    Sarif,
+    Html,
+}
+
+impl OutputFormat {
+    pub fn from_param(format: Option<&str>) -> Result<Self, String> {
+        match format.unwrap_or("json").to_lowercase().as_str() {
+            "json" => Ok(Self::Json),
+            "sarif" => Ok(Self::Sarif),
+            "html" => Ok(Self::Html),
+            other => Err(format!("Unknown format '{}': expected json, sarif or html", other)),
+        }
+    }
+}
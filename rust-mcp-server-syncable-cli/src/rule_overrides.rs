@@ -0,0 +1,148 @@
+// src/rule_overrides.rs
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use syncable_cli::analyzer::security::turbo::results::SecurityReport;
+use syncable_cli::analyzer::security::SecuritySeverity;
+
+use crate::{finding, security_baseline};
+use crate::severity::{parse_severity, security_severity};
+
+/// Project-level rule tuning, relative to the scanned project.
+pub const CONFIG_FILE: &str = ".syncable/security.toml";
+
+/// Contents of [`CONFIG_FILE`]:
+///
+/// ```toml
+/// disabled_rules = ["long-secret-value"]
+///
+/// [severity_overrides]
+/// "database-url-with-creds" = "medium"
+/// "env-api_key" = "low"
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RuleConfig {
+    pub disabled_rules: Vec<String>,
+    pub severity_overrides: HashMap<String, String>,
+}
+
+/// How many findings the configuration changed.
+#[derive(Debug, Serialize)]
+pub struct OverrideSummary {
+    pub overridden_findings: usize,
+    pub disabled_findings: usize,
+}
+
+fn severity_override(rule: &str, severity: &str) -> Result<SecuritySeverity, String> {
+    parse_severity(severity).map(security_severity).ok_or_else(|| {
+        format!(
+            "Invalid severity '{}' for rule '{}' in {}: expected critical, high, medium, low or info",
+            severity, rule, CONFIG_FILE
+        )
+    })
+}
+
+/// Loads the rule configuration, returning `None` if the project has none.
+pub fn load(project_path: &Path) -> Result<Option<RuleConfig>, String> {
+    let path = project_path.join(CONFIG_FILE);
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = std::fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    let config: RuleConfig =
+        toml::from_str(&content).map_err(|e| format!("Failed to parse {}: {}", path.display(), e))?;
+    for (rule, severity) in &config.severity_overrides {
+        severity_override(rule, severity)?;
+    }
+    Ok(Some(config))
+}
+
+/// Drops findings of disabled rules and re-grades overridden ones, then recounts the report.
+///
+/// Rules are matched by the rule part of the finding id, as shown in baselines and SARIF output.
+pub fn apply(report: &mut SecurityReport, config: &RuleConfig) -> OverrideSummary {
+    let before = report.findings.len();
+    let mut overridden_findings = 0;
+    report.findings.retain_mut(|finding| {
+        let rule_id = finding::rule_id(finding);
+        if config.disabled_rules.contains(&rule_id) {
+            return false;
+        }
+        if let Some(severity) = config.severity_overrides.get(&rule_id) {
+            // Validated in `load`.
+            if let Ok(severity) = severity_override(&rule_id, severity) {
+                if severity != finding.severity {
+                    finding.severity = severity;
+                    overridden_findings += 1;
+                }
+            }
+        }
+        true
+    });
+    let disabled_findings = before - report.findings.len();
+    if disabled_findings > 0 || overridden_findings > 0 {
+        security_baseline::recount(report);
+    }
+
+    OverrideSummary {
+        overridden_findings,
+        disabled_findings,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syncable_cli::analyzer::security::SecuritySeverity;
+
+    use super::*;
+    use crate::test_support::{report, secret};
+
+    fn project_with_config(config: &str) -> tempfile::TempDir {
+        let project = tempfile::tempdir().unwrap();
+        std::fs::create_dir(project.path().join(".syncable")).unwrap();
+        std::fs::write(project.path().join(CONFIG_FILE), config).unwrap();
+        project
+    }
+
+    #[test]
+    fn load_validates_severities() {
+        assert!(load(tempfile::tempdir().unwrap().path()).unwrap().is_none());
+
+        let project = project_with_config("[severity_overrides]\n\"long-secret-value\" = \"LOW\"\n");
+        assert!(load(project.path()).unwrap().is_some());
+
+        let project = project_with_config("[severity_overrides]\n\"long-secret-value\" = \"severe\"\n");
+        let error = load(project.path()).unwrap_err();
+        assert!(error.contains("Invalid severity 'severe' for rule 'long-secret-value'"), "{}", error);
+
+        let project = project_with_config("disabled = [\"long-secret-value\"]\n");
+        assert!(load(project.path()).is_err());
+    }
+
+    #[test]
+    fn apply_disables_and_regrades_rules() {
+        let mut report = report(vec![
+            secret("database-url-with-creds", "config.js", 1, SecuritySeverity::Critical),
+            secret("long-secret-value", "config.js", 2, SecuritySeverity::High),
+            secret("aws-access-key", "config.js", 3, SecuritySeverity::High),
+        ]);
+        let config = RuleConfig {
+            disabled_rules: vec!["long-secret-value".to_string()],
+            severity_overrides: HashMap::from([
+                ("database-url-with-creds".to_string(), "low".to_string()),
+                ("aws-access-key".to_string(), "high".to_string()),
+            ]),
+        };
+
+        let summary = apply(&mut report, &config);
+        assert_eq!(summary.disabled_findings, 1);
+        assert_eq!(summary.overridden_findings, 1);
+        assert_eq!(report.total_findings, 2);
+        assert_eq!(report.risk_level, SecuritySeverity::High);
+        assert_eq!(report.findings[1].severity, SecuritySeverity::Low);
+    }
+}
@@ -0,0 +1,305 @@
+// src/sarif.rs
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+use serde_json::{json, Value};
+use syncable_cli::analyzer::dependency_parser::Language;
+use syncable_cli::analyzer::security::turbo::results::SecurityReport;
+use syncable_cli::analyzer::vulnerability::VulnerabilityReport;
+
+use crate::severity::SeverityRank;
+use crate::{finding, owasp, security_baseline};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// Maps a severity rank (0 = critical .. 4 = info) to the SARIF level and the
+/// `security-severity` score GitHub Code Scanning uses to bucket alerts.
+fn level_and_score(rank: usize) -> (&'static str, &'static str) {
+    match rank {
+        0 => ("error", "9.5"),
+        1 => ("error", "8.0"),
+        2 => ("warning", "5.5"),
+        3 => ("note", "3.0"),
+        _ => ("note", "0.0"),
+    }
+}
+
+fn run(rules: BTreeMap<String, Value>, results: Vec<Value>, project_path: &Path) -> Value {
+    let mut src_root = project_path.to_string_lossy().replace('\\', "/");
+    if !src_root.ends_with('/') {
+        src_root.push('/');
+    }
+    if !src_root.starts_with('/') {
+        src_root.insert(0, '/');
+    }
+    json!({
+        "tool": {
+            "driver": {
+                "name": env!("CARGO_PKG_NAME"),
+                "informationUri": env!("CARGO_PKG_REPOSITORY"),
+                "version": env!("CARGO_PKG_VERSION"),
+                "rules": rules.into_values().collect::<Vec<_>>(),
+            }
+        },
+        "originalUriBaseIds": { "%SRCROOT%": { "uri": format!("file://{}", src_root) } },
+        "results": results,
+    })
+}
+
+fn log(run: Value) -> Value {
+    json!({ "$schema": SARIF_SCHEMA, "version": "2.1.0", "runs": [run] })
+}
+
+/// Converts a turbo security report into a SARIF 2.1.0 log.
+///
+/// Results carry the baseline fingerprint as a partial fingerprint, so code scanning
+/// tracks an alert across line moves the same way `security_scan` baselines do.
+pub fn security(report: &SecurityReport, project_path: &Path) -> Value {
+    let mut rules = BTreeMap::new();
+    let mut results = Vec::new();
+    let mut project_manifest = None;
+
+    for finding in &report.findings {
+        let rule_id = finding::rule_id(finding);
+        let file = finding::relative_file(finding, project_path);
+        let (level, score) = level_and_score(finding.severity.rank());
+
+        rules.entry(rule_id.clone()).or_insert_with(|| {
+            let mut tags = vec!["security".to_string(), format!("{:?}", finding.category)];
+            tags.extend(finding.cwe_id.iter().map(|cwe| format!("external/cwe/{}", cwe.to_lowercase())));
+            tags.extend(owasp::top10(finding).map(|category| format!("owasp-top10/{}", category)));
+            let mut rule = json!({
+                "id": rule_id,
+                "name": finding.title,
+                "shortDescription": { "text": finding.title },
+                "fullDescription": { "text": finding.description },
+                "defaultConfiguration": { "level": level },
+                "properties": { "tags": tags, "security-severity": score },
+            });
+            if !finding.remediation.is_empty() {
+                rule["help"] = json!({ "text": finding.remediation.join("\n") });
+            }
+            if let Some(uri) = finding.references.first() {
+                rule["helpUri"] = json!(uri);
+            }
+            rule
+        });
+
+        let mut result = json!({
+            "ruleId": rule_id,
+            "level": level,
+            "message": { "text": finding.description },
+            "partialFingerprints": {
+                "syncableFingerprint/v1": security_baseline::fingerprint(finding, project_path).fingerprint
+            },
+        });
+        let mut region = json!({ "startLine": finding.line_number.unwrap_or(1).max(1) });
+        if let Some(column) = finding.column_number.filter(|c| *c > 0) {
+            region["startColumn"] = json!(column);
+        }
+        // Findings without a file, such as environment variables, are reported against the project manifest.
+        let uri = file.unwrap_or_else(|| {
+            project_manifest
+                .get_or_insert_with(|| find_file(ANY_MANIFEST, project_path).unwrap_or_else(|| ".".to_string()))
+                .clone()
+        });
+        result["locations"] = location(&uri, region);
+        results.push(result);
+    }
+
+    log(run(rules, results, project_path))
+}
+
+/// Manifests and lockfiles a dependency of `language` may be declared in, most likely first.
+fn manifests(language: &Language) -> &'static [&'static str] {
+    match language {
+        Language::Rust => &["Cargo.toml", "Cargo.lock"],
+        Language::JavaScript | Language::TypeScript => {
+            &["package.json", "package-lock.json", "yarn.lock", "pnpm-lock.yaml"]
+        }
+        Language::Python => &["pyproject.toml", "requirements.txt", "Pipfile", "setup.py", "poetry.lock", "Pipfile.lock"],
+        Language::Go => &["go.mod", "go.sum"],
+        Language::Java | Language::Kotlin => &["pom.xml", "build.gradle", "build.gradle.kts"],
+        Language::Unknown => &[],
+    }
+}
+
+/// Manifests that identify a project regardless of its ecosystem.
+const ANY_MANIFEST: &[&str] = &["Cargo.toml", "package.json", "pyproject.toml", "requirements.txt", "go.mod", "pom.xml", "build.gradle"];
+
+/// Directories that hold dependencies or build output rather than the project's own manifests.
+const SKIPPED_DIRS: &[&str] = &["node_modules", "target", "vendor", "dist", "build"];
+
+/// Finds the first of `names` at the project root or, failing that, in a nested directory,
+/// as a project-relative path.
+fn find_file(names: &[&str], project_path: &Path) -> Option<String> {
+    if let Some(name) = names.iter().find(|name| project_path.join(name).is_file()) {
+        return Some(name.to_string());
+    }
+    if names.is_empty() {
+        return None;
+    }
+    let mut pending = vec![(project_path.to_path_buf(), 0)];
+    while let Some((dir, depth)) = pending.pop() {
+        let Ok(entries) = std::fs::read_dir(&dir) else { continue };
+        let mut entries: Vec<_> = entries.filter_map(Result::ok).map(|entry| entry.path()).collect();
+        entries.sort();
+        for path in entries.into_iter().rev() {
+            let name = path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+            let is_dir = std::fs::symlink_metadata(&path).is_ok_and(|meta| meta.is_dir());
+            if is_dir && depth < 4 && !name.starts_with('.') && !SKIPPED_DIRS.contains(&name.as_str()) {
+                pending.push((path, depth + 1));
+            } else if depth > 0 && names.contains(&name.as_str()) && path.is_file() {
+                let relative = path.strip_prefix(project_path).unwrap_or(&path);
+                return Some(relative.to_string_lossy().replace('\\', "/"));
+            }
+        }
+    }
+    None
+}
+
+fn location(uri: &str, region: Value) -> Value {
+    json!([{
+        "physicalLocation": {
+            "artifactLocation": { "uri": uri, "uriBaseId": "%SRCROOT%" },
+            "region": region,
+        }
+    }])
+}
+
+/// Converts a vulnerability report into a SARIF 2.1.0 log, with one rule per advisory.
+///
+/// Results point at the project's manifest for the dependency's ecosystem, since the
+/// checker does not record where a dependency was declared. Code scanning rejects results
+/// without a location, so when no manifest is found the ecosystem's usual one is named.
+pub fn vulnerabilities(report: &VulnerabilityReport, project_path: &Path) -> Value {
+    let mut rules = BTreeMap::new();
+    let mut results = Vec::new();
+    let mut manifest_paths: HashMap<&str, String> = HashMap::new();
+
+    for dep in &report.vulnerable_dependencies {
+        for vuln in &dep.vulnerabilities {
+            let (level, score) = level_and_score(vuln.severity.rank());
+
+            rules.entry(vuln.id.clone()).or_insert_with(|| {
+                let mut tags = vec!["security".to_string(), "dependency".to_string(), vuln.vuln_type.clone()];
+                tags.extend(vuln.cve.clone());
+                tags.push(format!("owasp-top10/{}", owasp::A06));
+                let mut rule = json!({
+                    "id": vuln.id,
+                    "name": vuln.title,
+                    "shortDescription": { "text": vuln.title },
+                    "fullDescription": { "text": vuln.description },
+                    "defaultConfiguration": { "level": level },
+                    "properties": { "tags": tags, "security-severity": score },
+                });
+                if let Some(patched) = &vuln.patched_versions {
+                    rule["help"] = json!({ "text": format!("Upgrade to a patched version: {}", patched) });
+                }
+                if let Some(uri) = vuln.references.first() {
+                    rule["helpUri"] = json!(uri);
+                }
+                rule
+            });
+
+            let mut result = json!({
+                "ruleId": vuln.id,
+                "level": level,
+                "message": {
+                    "text": format!("{} {} is affected by {}: {}", dep.name, dep.version, vuln.id, vuln.title)
+                },
+                "partialFingerprints": {
+                    "syncableFingerprint/v1": format!("{}:{}:{}", dep.language.as_str(), dep.name, vuln.id)
+                },
+            });
+            let manifest = manifest_paths.entry(dep.language.as_str()).or_insert_with(|| {
+                let names = manifests(&dep.language);
+                find_file(names, project_path)
+                    .or_else(|| names.first().map(|name| name.to_string()))
+                    .unwrap_or_else(|| ".".to_string())
+            });
+            result["locations"] = location(manifest, json!({ "startLine": 1 }));
+            results.push(result);
+        }
+    }
+
+    log(run(rules, results, project_path))
+}
+
+#[cfg(test)]
+mod tests {
+    use syncable_cli::analyzer::security::SecuritySeverity;
+    use syncable_cli::analyzer::vulnerability::VulnerabilitySeverity;
+
+    use super::*;
+    use crate::test_support::{advisory, project, report, secret, vulnerability_report};
+
+    #[test]
+    fn security_results_point_at_project_relative_files() {
+        let report = report(vec![
+            secret("database-url-with-creds", "config.js", 1, SecuritySeverity::Critical),
+            secret("database-url-with-creds", "src/cache.js", 4, SecuritySeverity::Medium),
+        ]);
+
+        let log = security(&report, project());
+        let run = &log["runs"][0];
+        assert_eq!(log["version"], "2.1.0");
+        assert_eq!(run["originalUriBaseIds"]["%SRCROOT%"]["uri"], "file:///project/");
+        assert_eq!(run["tool"]["driver"]["rules"].as_array().unwrap().len(), 1);
+        assert_eq!(run["tool"]["driver"]["rules"][0]["id"], "database-url-with-creds");
+
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(results[0]["ruleId"], "database-url-with-creds");
+        assert_eq!(results[0]["level"], "error");
+        assert_eq!(results[1]["level"], "warning");
+        let location = &results[1]["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "src/cache.js");
+        assert_eq!(location["region"]["startLine"], 4);
+        assert_eq!(
+            results[0]["partialFingerprints"]["syncableFingerprint/v1"],
+            security_baseline::fingerprint(&report.findings[0], project()).fingerprint
+        );
+    }
+
+    #[test]
+    fn results_without_a_file_point_at_a_manifest() {
+        let mut finding = secret("env-api_key", "config.js", 1, SecuritySeverity::Medium);
+        finding.file_path = None;
+        finding.line_number = None;
+
+        let log = security(&report(vec![finding]), project());
+        let location = &log["runs"][0]["results"][0]["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], ".");
+        assert_eq!(location["region"]["startLine"], 1);
+    }
+
+    #[test]
+    fn vulnerability_results_point_at_the_ecosystem_manifest() {
+        let project = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(project.path().join("web/node_modules/lodash")).unwrap();
+        std::fs::write(project.path().join("web/node_modules/lodash/package.json"), "{}").unwrap();
+        std::fs::write(project.path().join("web/package.json"), "{}").unwrap();
+        let mut report = vulnerability_report(vec![
+            ("lodash", vec![advisory("GHSA-jf85-cpcp-j695", None, VulnerabilitySeverity::High)]),
+            ("time", vec![advisory("RUSTSEC-2020-0071", Some("CVE-2020-26235"), VulnerabilitySeverity::Medium)]),
+        ]);
+        report.vulnerable_dependencies[1].language = Language::Rust;
+
+        let log = vulnerabilities(&report, project.path());
+        let run = &log["runs"][0];
+        assert_eq!(run["tool"]["driver"]["name"], "rust-mcp-server-syncable-cli");
+        let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+        assert_eq!(rules.len(), 2);
+        assert!(rules[1]["properties"]["tags"].as_array().unwrap().contains(&json!("CVE-2020-26235")));
+
+        let results = run["results"].as_array().unwrap();
+        let uri = |index: usize| results[index]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"].clone();
+        assert_eq!(results[0]["level"], "error");
+        assert_eq!(results[0]["partialFingerprints"]["syncableFingerprint/v1"], "JavaScript:lodash:GHSA-jf85-cpcp-j695");
+        // The nested manifest is found, not the copy under node_modules.
+        assert_eq!(uri(0), "web/package.json");
+        // Without a Cargo manifest in the project, the result still names the usual one.
+        assert_eq!(uri(1), "Cargo.toml");
+    }
+}
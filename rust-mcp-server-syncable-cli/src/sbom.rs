@@ -0,0 +1,715 @@
+// src/sbom.rs
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+use base64::Engine;
+use serde_json::{json, Value};
+use syncable_cli::analyzer::dependency_parser::{DependencyInfo, DependencyType, Language};
+
+/// An artifact digest recorded in a lockfile.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PackageHash {
+    /// CycloneDX algorithm name, e.g. `SHA-256`.
+    pub algorithm: &'static str,
+    pub hex: String,
+}
+
+/// Lockfile digests keyed by purl type, package name and exact version.
+pub type PackageHashes = HashMap<(&'static str, String, String), PackageHash>;
+
+/// Builds a CycloneDX 1.5 JSON document from the dependencies parsed for a project.
+pub fn cyclonedx_json(
+    project_name: &str,
+    dependencies: &HashMap<Language, Vec<DependencyInfo>>,
+    hashes: &PackageHashes,
+) -> Value {
+    let components: Vec<Value> = unique_dependencies(dependencies)
+        .into_iter()
+        .map(|(purl, dep)| {
+            let mut component = json!({
+                "type": "library",
+                "bom-ref": purl,
+                "name": dep.name,
+                "version": dep.version,
+                "scope": match dep.dep_type {
+                    DependencyType::Production => "required",
+                    DependencyType::Optional => "optional",
+                    DependencyType::Dev => "excluded",
+                },
+                "purl": purl,
+            });
+            if let Some(license) = cyclonedx_license(&dep.license) {
+                component["licenses"] = json!([license]);
+            }
+            if let Some(hash) = package_hash(dep, hashes) {
+                component["hashes"] = json!([{ "alg": hash.algorithm, "content": hash.hex }]);
+            }
+            component
+        })
+        .collect();
+
+    json!({
+        "bomFormat": "CycloneDX",
+        "specVersion": "1.5",
+        "serialNumber": format!("urn:uuid:{}", uuid::Uuid::new_v4()),
+        "version": 1,
+        "metadata": {
+            "timestamp": chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+            "tools": {
+                "components": [{
+                    "type": "application",
+                    "name": env!("CARGO_PKG_NAME"),
+                    "version": env!("CARGO_PKG_VERSION"),
+                }]
+            },
+            "component": {
+                "type": "application",
+                "bom-ref": project_name,
+                "name": project_name,
+            }
+        },
+        "components": components,
+    })
+}
+
+/// Flattens the per-language map into purl-keyed entries, sorted and de-duplicated.
+fn unique_dependencies(
+    dependencies: &HashMap<Language, Vec<DependencyInfo>>,
+) -> BTreeMap<String, &DependencyInfo> {
+    let mut unique = BTreeMap::new();
+    for dep in dependencies.values().flatten() {
+        unique.entry(package_url(dep)).or_insert(dep);
+    }
+    unique
+}
+
+fn purl_type(language: &Language) -> &'static str {
+    match language {
+        Language::Rust => "cargo",
+        Language::JavaScript | Language::TypeScript => "npm",
+        Language::Python => "pypi",
+        Language::Go => "golang",
+        Language::Java | Language::Kotlin => "maven",
+        Language::Unknown => "generic",
+    }
+}
+
+/// Percent-encodes a purl namespace, name or version segment.
+fn purl_encode(segment: &str) -> String {
+    let mut encoded = String::new();
+    for byte in segment.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'.' | b'-' | b'_' | b'~') {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    encoded
+}
+
+/// Returns the package URL (purl) identifying a dependency in its ecosystem.
+///
+/// The version is only included when it is exact, since a purl names a single release.
+pub fn package_url(dep: &DependencyInfo) -> String {
+    let name = match dep.language {
+        Language::Python => dep.name.to_lowercase().replace('_', "-"),
+        Language::Java | Language::Kotlin => dep.name.replacen(':', "/", 1),
+        _ => dep.name.clone(),
+    };
+    // Scopes, Maven groups and Go module paths become `/`-separated namespace segments
+    let path = name.split('/').map(purl_encode).collect::<Vec<_>>().join("/");
+    match exact_version(&dep.version) {
+        Some(version) => format!("pkg:{}/{}@{}", purl_type(&dep.language), path, purl_encode(version)),
+        None => format!("pkg:{}/{}", purl_type(&dep.language), path),
+    }
+}
+
+/// Returns the version if it names a single release, as lockfiles do; ranges and
+/// wildcards (`^1.2.0`, `>=1.0, <2.0`, `1.x`, `*`) yield `None`.
+fn exact_version(version: &str) -> Option<&str> {
+    let version = version.trim();
+    let version = version.strip_prefix("==").or_else(|| version.strip_prefix('=')).unwrap_or(version);
+    let is_range = version.is_empty()
+        || version == "latest"
+        || version.contains(|c: char| c.is_whitespace() || "^~<>=!,|*".contains(c))
+        || version.split('.').any(|part| part.eq_ignore_ascii_case("x"));
+    (!is_range).then_some(version)
+}
+
+fn package_hash<'a>(dep: &DependencyInfo, hashes: &'a PackageHashes) -> Option<&'a PackageHash> {
+    let version = exact_version(&dep.version)?;
+    hashes.get(&(purl_type(&dep.language), dep.name.clone(), version.to_string()))
+}
+
+/// Reads artifact digests from the project's `Cargo.lock` and `package-lock.json`.
+///
+/// Other lockfiles don't record plain artifact digests, so their packages have no hashes.
+pub fn lockfile_hashes(project_path: &Path) -> PackageHashes {
+    let mut hashes = PackageHashes::new();
+    if let Ok(content) = std::fs::read_to_string(project_path.join("Cargo.lock")) {
+        match toml::from_str::<toml::Value>(&content) {
+            Ok(lock) => cargo_lock_hashes(&lock, &mut hashes),
+            Err(e) => eprintln!("⚠️  Skipping Cargo.lock checksums: {}", e),
+        }
+    }
+    if let Ok(content) = std::fs::read_to_string(project_path.join("package-lock.json")) {
+        match serde_json::from_str::<Value>(&content) {
+            Ok(lock) => npm_lock_hashes(&lock, &mut hashes),
+            Err(e) => eprintln!("⚠️  Skipping package-lock.json integrity hashes: {}", e),
+        }
+    }
+    hashes
+}
+
+fn cargo_lock_hashes(lock: &toml::Value, hashes: &mut PackageHashes) {
+    for package in lock.get("package").and_then(|p| p.as_array()).into_iter().flatten() {
+        let field = |key: &str| package.get(key).and_then(|v| v.as_str());
+        if let (Some(name), Some(version), Some(checksum)) = (field("name"), field("version"), field("checksum")) {
+            let hash = PackageHash { algorithm: "SHA-256", hex: checksum.to_lowercase() };
+            hashes.insert(("cargo", name.to_string(), version.to_string()), hash);
+        }
+    }
+}
+
+fn npm_lock_hashes(lock: &Value, hashes: &mut PackageHashes) {
+    let mut insert = |name: &str, entry: &Value| {
+        let version = entry["version"].as_str();
+        let hash = entry["integrity"].as_str().and_then(integrity_hash);
+        if let (Some(version), Some(hash)) = (version, hash) {
+            hashes.insert(("npm", name.to_string(), version.to_string()), hash);
+        }
+    };
+    // Lockfile v2/v3 lists every installed package by its node_modules path
+    for (path, entry) in lock["packages"].as_object().into_iter().flatten() {
+        if let Some((_, name)) = path.rsplit_once("node_modules/") {
+            insert(name, entry);
+        }
+    }
+    // Lockfile v1 nests transitive dependencies under their parent
+    let mut pending: Vec<&Value> = vec![&lock["dependencies"]];
+    while let Some(dependencies) = pending.pop() {
+        for (name, entry) in dependencies.as_object().into_iter().flatten() {
+            insert(name, entry);
+            pending.push(&entry["dependencies"]);
+        }
+    }
+}
+
+/// Converts the strongest digest in a Subresource Integrity string (`sha512-<base64>`) to hex.
+fn integrity_hash(integrity: &str) -> Option<PackageHash> {
+    ["sha512", "sha384", "sha256", "sha1"].into_iter().find_map(|prefix| {
+        let digest = integrity
+            .split_whitespace()
+            .find_map(|part| part.strip_prefix(prefix)?.strip_prefix('-'))?;
+        let bytes = base64::engine::general_purpose::STANDARD.decode(digest).ok()?;
+        let algorithm = match prefix {
+            "sha512" => "SHA-512",
+            "sha384" => "SHA-384",
+            "sha256" => "SHA-256",
+            _ => "SHA-1",
+        };
+        Some(PackageHash { algorithm, hex: bytes.iter().map(|b| format!("{:02x}", b)).collect() })
+    })
+}
+
+fn cyclonedx_license(license: &str) -> Option<Value> {
+    Some(match declared_license(license)? {
+        // Single ids go under `license.id`; compound expressions and `+` need `expression`
+        DeclaredLicense::Spdx(id) if !id.contains([' ', '+']) => json!({ "license": { "id": id } }),
+        DeclaredLicense::Spdx(expression) => json!({ "expression": expression }),
+        DeclaredLicense::Other(name) => json!({ "license": { "name": name } }),
+    })
+}
+
+/// Builds an SPDX 2.3 JSON document from the dependencies parsed for a project.
+pub fn spdx_json(
+    project_name: &str,
+    dependencies: &HashMap<Language, Vec<DependencyInfo>>,
+    hashes: &PackageHashes,
+) -> Value {
+    const ROOT_ID: &str = "SPDXRef-RootPackage";
+
+    let mut packages = vec![json!({
+        "SPDXID": ROOT_ID,
+        "name": project_name,
+        "downloadLocation": "NOASSERTION",
+        "filesAnalyzed": false,
+    })];
+    let mut relationships = vec![json!({
+        "spdxElementId": "SPDXRef-DOCUMENT",
+        "relationshipType": "DESCRIBES",
+        "relatedSpdxElement": ROOT_ID,
+    })];
+
+    // Free-form licenses, keyed by their LicenseRef id
+    let mut extracted_licenses = BTreeMap::new();
+    for (index, (purl, dep)) in unique_dependencies(dependencies).into_iter().enumerate() {
+        let spdx_id = format!("SPDXRef-Package-{}", index + 1);
+        let license_declared = match declared_license(&dep.license) {
+            Some(DeclaredLicense::Spdx(expression)) => expression,
+            Some(DeclaredLicense::Other(text)) => {
+                let id = license_ref(&text);
+                extracted_licenses.entry(id.clone()).or_insert(text);
+                id
+            }
+            None => "NOASSERTION".to_string(),
+        };
+        let mut package = json!({
+            "SPDXID": spdx_id,
+            "name": dep.name,
+            "downloadLocation": "NOASSERTION",
+            "filesAnalyzed": false,
+            "licenseConcluded": "NOASSERTION",
+            "licenseDeclared": license_declared,
+            "copyrightText": "NOASSERTION",
+            "externalRefs": [{
+                "referenceCategory": "PACKAGE-MANAGER",
+                "referenceType": "purl",
+                "referenceLocator": purl,
+            }],
+        });
+        if let Some(version) = exact_version(&dep.version) {
+            package["versionInfo"] = json!(version);
+        }
+        if let Some(hash) = package_hash(dep, hashes) {
+            package["checksums"] = json!([{
+                "algorithm": hash.algorithm.replace('-', ""),
+                "checksumValue": hash.hex,
+            }]);
+        }
+        packages.push(package);
+
+        // Dev dependencies point back at the project rather than being depended on by it
+        relationships.push(match dep.dep_type {
+            DependencyType::Dev => json!({
+                "spdxElementId": spdx_id,
+                "relationshipType": "DEV_DEPENDENCY_OF",
+                "relatedSpdxElement": ROOT_ID,
+            }),
+            DependencyType::Optional => json!({
+                "spdxElementId": spdx_id,
+                "relationshipType": "OPTIONAL_DEPENDENCY_OF",
+                "relatedSpdxElement": ROOT_ID,
+            }),
+            DependencyType::Production => json!({
+                "spdxElementId": ROOT_ID,
+                "relationshipType": "DEPENDS_ON",
+                "relatedSpdxElement": spdx_id,
+            }),
+        });
+    }
+
+    let mut document = json!({
+        "spdxVersion": "SPDX-2.3",
+        "dataLicense": "CC0-1.0",
+        "SPDXID": "SPDXRef-DOCUMENT",
+        "name": project_name,
+        "documentNamespace": format!(
+            "https://spdx.org/spdxdocs/{}-{}",
+            project_name.replace(|c: char| !c.is_ascii_alphanumeric() && c != '-' && c != '.', "-"),
+            uuid::Uuid::new_v4()
+        ),
+        "creationInfo": {
+            "created": chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+            "creators": [format!("Tool: {}-{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"))],
+        },
+        "packages": packages,
+        "relationships": relationships,
+    });
+    if !extracted_licenses.is_empty() {
+        document["hasExtractedLicensingInfos"] = extracted_licenses
+            .into_iter()
+            .map(|(id, text)| json!({ "licenseId": id, "extractedText": text, "name": text }))
+            .collect();
+    }
+    document
+}
+
+/// Renders a document produced by [`spdx_json`] in the SPDX tag-value format.
+pub fn spdx_tag_value(document: &Value) -> String {
+    let field = |value: &Value, key: &str| value[key].as_str().unwrap_or("NOASSERTION").to_string();
+    let mut out = String::new();
+
+    out.push_str(&format!("SPDXVersion: {}\n", field(document, "spdxVersion")));
+    out.push_str(&format!("DataLicense: {}\n", field(document, "dataLicense")));
+    out.push_str(&format!("SPDXID: {}\n", field(document, "SPDXID")));
+    out.push_str(&format!("DocumentName: {}\n", field(document, "name")));
+    out.push_str(&format!("DocumentNamespace: {}\n", field(document, "documentNamespace")));
+    for creator in document["creationInfo"]["creators"].as_array().into_iter().flatten() {
+        out.push_str(&format!("Creator: {}\n", creator.as_str().unwrap_or_default()));
+    }
+    out.push_str(&format!("Created: {}\n", field(&document["creationInfo"], "created")));
+
+    for package in document["packages"].as_array().into_iter().flatten() {
+        out.push('\n');
+        out.push_str(&format!("PackageName: {}\n", field(package, "name")));
+        out.push_str(&format!("SPDXID: {}\n", field(package, "SPDXID")));
+        if let Some(version) = package["versionInfo"].as_str() {
+            out.push_str(&format!("PackageVersion: {}\n", version));
+        }
+        out.push_str(&format!("PackageDownloadLocation: {}\n", field(package, "downloadLocation")));
+        for checksum in package["checksums"].as_array().into_iter().flatten() {
+            out.push_str(&format!(
+                "PackageChecksum: {}: {}\n",
+                field(checksum, "algorithm"),
+                field(checksum, "checksumValue")
+            ));
+        }
+        out.push_str("FilesAnalyzed: false\n");
+        if package.get("licenseDeclared").is_some() {
+            out.push_str(&format!("PackageLicenseConcluded: {}\n", field(package, "licenseConcluded")));
+            out.push_str(&format!("PackageLicenseDeclared: {}\n", field(package, "licenseDeclared")));
+            out.push_str(&format!("PackageCopyrightText: {}\n", field(package, "copyrightText")));
+        }
+        for reference in package["externalRefs"].as_array().into_iter().flatten() {
+            out.push_str(&format!(
+                "ExternalRef: {} {} {}\n",
+                field(reference, "referenceCategory"),
+                field(reference, "referenceType"),
+                field(reference, "referenceLocator")
+            ));
+        }
+    }
+
+    for license in document["hasExtractedLicensingInfos"].as_array().into_iter().flatten() {
+        out.push('\n');
+        out.push_str(&format!("LicenseID: {}\n", field(license, "licenseId")));
+        out.push_str(&format!("ExtractedText: <text>{}</text>\n", field(license, "extractedText")));
+        out.push_str(&format!("LicenseName: {}\n", field(license, "name")));
+    }
+
+    out.push('\n');
+    for relationship in document["relationships"].as_array().into_iter().flatten() {
+        out.push_str(&format!(
+            "Relationship: {} {} {}\n",
+            field(relationship, "spdxElementId"),
+            field(relationship, "relationshipType"),
+            field(relationship, "relatedSpdxElement")
+        ));
+    }
+    out
+}
+
+/// SPDX license ids commonly declared in package manifests, including deprecated ids that
+/// remain valid. Licenses outside this list are written as `LicenseRef-` references.
+const SPDX_LICENSE_IDS: &[&str] = &[
+    "0BSD", "AFL-2.1", "AFL-3.0", "AGPL-3.0", "AGPL-3.0-only", "AGPL-3.0-or-later", "Apache-1.1",
+    "Apache-2.0", "APSL-2.0", "Artistic-1.0", "Artistic-2.0", "BlueOak-1.0.0", "BSD-1-Clause",
+    "BSD-2-Clause", "BSD-2-Clause-Patent", "BSD-3-Clause", "BSD-3-Clause-Clear", "BSD-4-Clause",
+    "BSL-1.0", "bzip2-1.0.6", "CAL-1.0", "CC-BY-3.0", "CC-BY-4.0", "CC-BY-SA-3.0", "CC-BY-SA-4.0",
+    "CC0-1.0", "CDDL-1.0", "CDDL-1.1", "CECILL-2.1", "CPL-1.0", "ECL-2.0", "EPL-1.0", "EPL-2.0",
+    "EUPL-1.1", "EUPL-1.2", "GFDL-1.3-only", "GFDL-1.3-or-later", "GPL-1.0-or-later", "GPL-2.0",
+    "GPL-2.0-only", "GPL-2.0-or-later", "GPL-3.0", "GPL-3.0-only", "GPL-3.0-or-later", "HPND", "ICU",
+    "ISC", "JSON", "LGPL-2.0", "LGPL-2.0-only", "LGPL-2.0-or-later", "LGPL-2.1", "LGPL-2.1-only",
+    "LGPL-2.1-or-later", "LGPL-3.0", "LGPL-3.0-only", "LGPL-3.0-or-later", "libpng-2.0", "MIT",
+    "MIT-0", "MPL-1.0", "MPL-1.1", "MPL-2.0", "MPL-2.0-no-copyleft-exception", "MS-PL", "MS-RL",
+    "MulanPSL-2.0", "NCSA", "ODbL-1.0", "OFL-1.1", "OpenSSL", "OSL-3.0", "PHP-3.01", "PostgreSQL",
+    "PSF-2.0", "Python-2.0", "Ruby", "SSPL-1.0", "Unicode-3.0", "Unicode-DFS-2016", "Unlicense",
+    "UPL-1.0", "Vim", "W3C", "WTFPL", "X11", "Zlib", "zlib-acknowledgement", "ZPL-2.1",
+];
+
+/// SPDX license exceptions allowed after `WITH`.
+const SPDX_EXCEPTION_IDS: &[&str] = &[
+    "Autoconf-exception-3.0", "Bison-exception-2.2", "Classpath-exception-2.0", "GCC-exception-3.1",
+    "LLVM-exception", "OpenJDK-assembly-exception-1.0", "openvpn-openssl-exception",
+];
+
+/// A manifest license, as an SPDX expression when it is one.
+#[derive(Debug, PartialEq)]
+enum DeclaredLicense {
+    /// A valid SPDX expression, with ids and operators in canonical case.
+    Spdx(String),
+    /// Free-form text such as `BSD style`.
+    Other(String),
+}
+
+fn declared_license(license: &str) -> Option<DeclaredLicense> {
+    let license = license.trim();
+    if license.is_empty() || license.eq_ignore_ascii_case("unknown") || license == "NOASSERTION" {
+        return None;
+    }
+    // Cargo still accepts the legacy `MIT/Apache-2.0` form for dual licensing
+    Some(match spdx_expression(&license.replace('/', " OR ")) {
+        Some(expression) => DeclaredLicense::Spdx(expression),
+        None => DeclaredLicense::Other(license.to_string()),
+    })
+}
+
+/// Parses `license` as an SPDX license expression of known ids, returning it in canonical form.
+fn spdx_expression(license: &str) -> Option<String> {
+    let spaced = license.replace('(', " ( ").replace(')', " ) ");
+    let mut tokens = spaced.split_whitespace().peekable();
+    let mut out = Vec::new();
+    parse_or(&mut tokens, &mut out)?;
+    if tokens.next().is_some() {
+        return None;
+    }
+    Some(out.join(" ").replace("( ", "(").replace(" )", ")"))
+}
+
+type Tokens<'a> = std::iter::Peekable<std::str::SplitWhitespace<'a>>;
+
+fn parse_or(tokens: &mut Tokens, out: &mut Vec<String>) -> Option<()> {
+    parse_and(tokens, out)?;
+    while tokens.peek().is_some_and(|t| t.eq_ignore_ascii_case("OR")) {
+        tokens.next();
+        out.push("OR".to_string());
+        parse_and(tokens, out)?;
+    }
+    Some(())
+}
+
+fn parse_and(tokens: &mut Tokens, out: &mut Vec<String>) -> Option<()> {
+    parse_term(tokens, out)?;
+    while tokens.peek().is_some_and(|t| t.eq_ignore_ascii_case("AND")) {
+        tokens.next();
+        out.push("AND".to_string());
+        parse_term(tokens, out)?;
+    }
+    Some(())
+}
+
+fn parse_term(tokens: &mut Tokens, out: &mut Vec<String>) -> Option<()> {
+    let token = tokens.next()?;
+    if token == "(" {
+        out.push("(".to_string());
+        parse_or(tokens, out)?;
+        (tokens.next()? == ")").then_some(())?;
+        out.push(")".to_string());
+        return Some(());
+    }
+    let (id, or_later) = match token.strip_suffix('+') {
+        Some(id) => (id, "+"),
+        None => (token, ""),
+    };
+    let id = SPDX_LICENSE_IDS.iter().find(|known| known.eq_ignore_ascii_case(id))?;
+    out.push(format!("{}{}", id, or_later));
+    if tokens.peek().is_some_and(|t| t.eq_ignore_ascii_case("WITH")) {
+        tokens.next();
+        let exception = tokens.next()?;
+        let exception = SPDX_EXCEPTION_IDS.iter().find(|known| known.eq_ignore_ascii_case(exception))?;
+        out.push("WITH".to_string());
+        out.push(exception.to_string());
+    }
+    Some(())
+}
+
+/// The `LicenseRef-` id used for a license that is not an SPDX expression.
+fn license_ref(license: &str) -> String {
+    let sanitized: String = license
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '.' || c == '-' { c } else { '-' })
+        .collect();
+    format!("LicenseRef-{}", sanitized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dependency(name: &str, version: &str, license: &str, language: Language) -> DependencyInfo {
+        DependencyInfo {
+            name: name.to_string(),
+            version: version.to_string(),
+            dep_type: DependencyType::Production,
+            license: license.to_string(),
+            source: None,
+            language,
+        }
+    }
+
+    #[test]
+    fn builds_encoded_package_urls() {
+        let purl = |name, version, language| package_url(&dependency(name, version, "", language));
+        assert_eq!(purl("serde", "1.0.197", Language::Rust), "pkg:cargo/serde@1.0.197");
+        assert_eq!(purl("@types/node", "20.1.0", Language::TypeScript), "pkg:npm/%40types/node@20.1.0");
+        assert_eq!(purl("Django_Rest", "==3.14.0", Language::Python), "pkg:pypi/django-rest@3.14.0");
+        assert_eq!(purl("org.slf4j:slf4j-api", "2.0.9", Language::Java), "pkg:maven/org.slf4j/slf4j-api@2.0.9");
+        assert_eq!(purl("github.com/pkg/errors", "v0.9.1", Language::Go), "pkg:golang/github.com/pkg/errors@v0.9.1");
+        assert_eq!(purl("left pad", "1.0.0+build", Language::JavaScript), "pkg:npm/left%20pad@1.0.0%2Bbuild");
+        // Ranges don't name a release, so the purl leaves the version out
+        assert_eq!(purl("requests", ">=1.0, <2.0", Language::Python), "pkg:pypi/requests");
+        assert_eq!(purl("lodash", "^4.17.21", Language::JavaScript), "pkg:npm/lodash");
+    }
+
+    #[test]
+    fn only_exact_versions_are_pinned() {
+        assert_eq!(exact_version("1.2.3"), Some("1.2.3"));
+        assert_eq!(exact_version("=1.2.3"), Some("1.2.3"));
+        assert_eq!(exact_version("==2.31.0"), Some("2.31.0"));
+        assert_eq!(exact_version("1.0.0-beta.1"), Some("1.0.0-beta.1"));
+        for range in ["", "*", "latest", "^1.2.3", "~1.2", ">=1.0, <2.0", "1.x", "1.2.*", "1 || 2", "!=1.0"] {
+            assert_eq!(exact_version(range), None, "{range}");
+        }
+    }
+
+    #[test]
+    fn maps_cyclonedx_licenses() {
+        assert_eq!(cyclonedx_license("mit"), Some(json!({ "license": { "id": "MIT" } })));
+        assert_eq!(
+            cyclonedx_license("MIT/Apache-2.0"),
+            Some(json!({ "expression": "MIT OR Apache-2.0" }))
+        );
+        assert_eq!(cyclonedx_license("GPL-2.0+"), Some(json!({ "expression": "GPL-2.0+" })));
+        assert_eq!(cyclonedx_license("BSD style"), Some(json!({ "license": { "name": "BSD style" } })));
+        assert_eq!(cyclonedx_license("Unknown"), None);
+    }
+
+    #[test]
+    fn reads_lockfile_hashes() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("Cargo.lock"),
+            r#"
+version = 3
+
+[[package]]
+name = "demo"
+version = "0.1.0"
+
+[[package]]
+name = "serde"
+version = "1.0.197"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "3FB1C873E1B9B056A4DC4C0C198B24C3FFA059243875552B2BD0933B1AEE4CE2"
+"#,
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("package-lock.json"),
+            r#"{
+  "lockfileVersion": 3,
+  "packages": {
+    "": { "name": "demo" },
+    "node_modules/@types/node": { "version": "20.1.0", "integrity": "sha1-AAA= sha512-AAEC/w==" },
+    "node_modules/a/node_modules/b": { "version": "1.0.0", "integrity": "sha256-3q2+7w==" }
+  }
+}"#,
+        )
+        .unwrap();
+
+        let hashes = lockfile_hashes(dir.path());
+        assert_eq!(hashes.len(), 3);
+        let hash = |kind, name: &str, version: &str| hashes.get(&(kind, name.to_string(), version.to_string()));
+        assert_eq!(
+            hash("cargo", "serde", "1.0.197"),
+            Some(&PackageHash {
+                algorithm: "SHA-256",
+                hex: "3fb1c873e1b9b056a4dc4c0c198b24c3ffa059243875552b2bd0933b1aee4ce2".to_string(),
+            })
+        );
+        assert_eq!(
+            hash("npm", "@types/node", "20.1.0"),
+            Some(&PackageHash { algorithm: "SHA-512", hex: "000102ff".to_string() })
+        );
+        assert_eq!(hash("npm", "b", "1.0.0").unwrap().hex, "deadbeef");
+
+        let mut legacy = PackageHashes::new();
+        npm_lock_hashes(
+            &json!({ "dependencies": { "a": {
+                "version": "1.0.0",
+                "integrity": "sha512-3q2+7w==",
+                "dependencies": { "b": { "version": "2.0.0", "integrity": "sha512-AAEC/w==" } }
+            } } }),
+            &mut legacy,
+        );
+        assert_eq!(legacy.len(), 2);
+        assert!(legacy.contains_key(&("npm", "b".to_string(), "2.0.0".to_string())));
+    }
+
+    #[test]
+    fn documents_include_lockfile_hashes() {
+        let dependencies = HashMap::from([(
+            Language::Rust,
+            vec![
+                dependency("serde", "1.0.197", "MIT OR Apache-2.0", Language::Rust),
+                dependency("local", "0.1.0", "MIT", Language::Rust),
+            ],
+        )]);
+        let hashes = PackageHashes::from([(
+            ("cargo", "serde".to_string(), "1.0.197".to_string()),
+            PackageHash { algorithm: "SHA-256", hex: "abcd".to_string() },
+        )]);
+
+        let bom = cyclonedx_json("demo", &dependencies, &hashes);
+        let components = bom["components"].as_array().unwrap();
+        let serde = components.iter().find(|c| c["name"] == "serde").unwrap();
+        assert_eq!(serde["hashes"], json!([{ "alg": "SHA-256", "content": "abcd" }]));
+        let local = components.iter().find(|c| c["name"] == "local").unwrap();
+        assert!(local.get("hashes").is_none());
+        assert_eq!(local["licenses"], json!([{ "license": { "id": "MIT" } }]));
+
+        let document = spdx_json("demo", &dependencies, &hashes);
+        let serde = document["packages"].as_array().unwrap().iter().find(|p| p["name"] == "serde").unwrap();
+        assert_eq!(serde["checksums"], json!([{ "algorithm": "SHA256", "checksumValue": "abcd" }]));
+        assert!(spdx_tag_value(&document).contains("PackageChecksum: SHA256: abcd\n"));
+    }
+
+    #[test]
+    fn normalizes_spdx_expressions() {
+        assert_eq!(spdx_expression("MIT"), Some("MIT".to_string()));
+        assert_eq!(spdx_expression("mit or apache-2.0"), Some("MIT OR Apache-2.0".to_string()));
+        assert_eq!(
+            spdx_expression("(MIT OR Apache-2.0) AND BSD-3-Clause"),
+            Some("(MIT OR Apache-2.0) AND BSD-3-Clause".to_string())
+        );
+        assert_eq!(
+            spdx_expression("Apache-2.0 WITH LLVM-exception"),
+            Some("Apache-2.0 WITH LLVM-exception".to_string())
+        );
+        assert_eq!(spdx_expression("GPL-2.0+"), Some("GPL-2.0+".to_string()));
+        assert_eq!(spdx_expression("MIT OR Apache"), None);
+        assert_eq!(spdx_expression("BSD style"), None);
+        assert_eq!(spdx_expression("(MIT"), None);
+        assert_eq!(spdx_expression("MIT AND"), None);
+        assert_eq!(spdx_expression("MIT WITH Apache-2.0"), None);
+    }
+
+    #[test]
+    fn classifies_declared_licenses() {
+        assert_eq!(declared_license(" "), None);
+        assert_eq!(declared_license("Unknown"), None);
+        assert_eq!(
+            declared_license("MIT/Apache-2.0"),
+            Some(DeclaredLicense::Spdx("MIT OR Apache-2.0".to_string()))
+        );
+        assert_eq!(declared_license("MIT/Apache"), Some(DeclaredLicense::Other("MIT/Apache".to_string())));
+        assert_eq!(license_ref("MIT/Apache"), "LicenseRef-MIT-Apache");
+        assert_eq!(license_ref("BSD style"), "LicenseRef-BSD-style");
+    }
+
+    #[test]
+    fn spdx_documents_reference_extracted_licenses() {
+        let dependencies = HashMap::from([(
+            Language::Rust,
+            vec![
+                dependency("serde", "1.0.0", "MIT OR Apache-2.0", Language::Rust),
+                dependency("legacy", "0.1.0", "BSD style", Language::Rust),
+                dependency("other", "0.2.0", "BSD style", Language::Rust),
+                dependency("mystery", "0.3.0", "", Language::Rust),
+            ],
+        )]);
+
+        let document = spdx_json("demo", &dependencies, &PackageHashes::new());
+        let declared: BTreeMap<&str, &str> = document["packages"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .skip(1)
+            .map(|p| (p["name"].as_str().unwrap(), p["licenseDeclared"].as_str().unwrap()))
+            .collect();
+        assert_eq!(declared["serde"], "MIT OR Apache-2.0");
+        assert_eq!(declared["legacy"], "LicenseRef-BSD-style");
+        assert_eq!(declared["other"], "LicenseRef-BSD-style");
+        assert_eq!(declared["mystery"], "NOASSERTION");
+        assert_eq!(
+            document["hasExtractedLicensingInfos"],
+            json!([{ "licenseId": "LicenseRef-BSD-style", "extractedText": "BSD style", "name": "BSD style" }])
+        );
+
+        let tag_value = spdx_tag_value(&document);
+        assert!(tag_value.contains("PackageLicenseDeclared: LicenseRef-BSD-style\n"));
+        assert!(tag_value.contains("LicenseID: LicenseRef-BSD-style\nExtractedText: <text>BSD style</text>\n"));
+    }
+}
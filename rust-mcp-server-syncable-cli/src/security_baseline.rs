@@ -0,0 +1,144 @@
+// src/security_baseline.rs
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha1::{Digest, Sha1};
+use syncable_cli::analyzer::security::turbo::results::{ResultAggregator, SecurityReport};
+use syncable_cli::analyzer::security::SecurityFinding;
+
+use crate::finding;
+
+/// A finding recorded in a baseline file.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    pub fingerprint: String,
+    pub rule_id: String,
+    pub file: Option<String>,
+    pub title: String,
+}
+
+/// Findings that existed when the baseline was recorded.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SecurityBaseline {
+    pub created_at: DateTime<Utc>,
+    pub findings: Vec<BaselineEntry>,
+}
+
+/// Summary of the comparison against the baseline.
+#[derive(Debug, Serialize)]
+pub struct BaselineSummary {
+    pub created_at: DateTime<Utc>,
+    /// True when this scan recorded the baseline because `update_baseline` was set.
+    pub recorded: bool,
+    pub suppressed_count: usize,
+    pub new_count: usize,
+    /// Baseline entries no longer found, e.g. because the secret was removed.
+    pub resolved_count: usize,
+}
+
+/// Identifies a finding independently of its line number and of where the project is checked out.
+///
+/// Built from the rule, the project-relative path and the matched evidence.
+pub fn fingerprint(finding: &SecurityFinding, project_path: &Path) -> BaselineEntry {
+    let rule_id = finding::rule_id(finding);
+    let file = finding::relative_file(finding, project_path);
+
+    let mut hasher = Sha1::new();
+    hasher.update(rule_id.as_bytes());
+    hasher.update([0]);
+    hasher.update(file.as_deref().unwrap_or_default().as_bytes());
+    hasher.update([0]);
+    hasher.update(finding.evidence.as_deref().unwrap_or_default().trim().as_bytes());
+
+    BaselineEntry {
+        fingerprint: format!("{:x}", hasher.finalize()),
+        rule_id,
+        file,
+        title: finding.title.clone(),
+    }
+}
+
+/// Reads a baseline, returning `None` when the file does not exist yet.
+pub fn load(path: &Path) -> Result<Option<SecurityBaseline>, String> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read baseline {}: {}", path.display(), e))?;
+    serde_json::from_str(&content)
+        .map(Some)
+        .map_err(|e| format!("Failed to parse baseline {}: {}", path.display(), e))
+}
+
+/// Records every finding in `report` as the new baseline.
+pub fn record(path: &Path, report: &SecurityReport, project_path: &Path) -> Result<SecurityBaseline, String> {
+    let baseline = SecurityBaseline {
+        created_at: Utc::now(),
+        findings: report
+            .findings
+            .iter()
+            .map(|finding| fingerprint(finding, project_path))
+            .collect(),
+    };
+    let json = serde_json::to_string_pretty(&baseline).map_err(|e| e.to_string())?;
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+    }
+    std::fs::write(path, json)
+        .map_err(|e| format!("Failed to write baseline {}: {}", path.display(), e))?;
+    Ok(baseline)
+}
+
+/// Drops findings recorded in `baseline` from `report` and recounts the totals.
+///
+/// When only some files were scanned, `covered` lists them so entries for other files
+/// are not counted as resolved.
+pub fn apply(
+    report: &mut SecurityReport,
+    baseline: &SecurityBaseline,
+    project_path: &Path,
+    recorded: bool,
+    covered: Option<&[String]>,
+) -> BaselineSummary {
+    let known: HashSet<&str> = baseline.findings.iter().map(|e| e.fingerprint.as_str()).collect();
+    let in_scope = |entry: &BaselineEntry| match (covered, &entry.file) {
+        (Some(files), Some(file)) => files.contains(file),
+        _ => true,
+    };
+    let mut seen = HashSet::new();
+    let before = report.findings.len();
+    report.findings.retain(|finding| {
+        let entry = fingerprint(finding, project_path);
+        let suppressed = known.contains(entry.fingerprint.as_str());
+        seen.insert(entry.fingerprint);
+        !suppressed
+    });
+
+    recount(report);
+
+    BaselineSummary {
+        created_at: baseline.created_at,
+        recorded,
+        suppressed_count: before - report.findings.len(),
+        new_count: report.findings.len(),
+        resolved_count: baseline
+            .findings
+            .iter()
+            .filter(|entry| in_scope(entry) && !seen.contains(&entry.fingerprint))
+            .count(),
+    }
+}
+
+/// Recomputes the totals, score, risk level and recommendations of `report` after
+/// findings were added, removed or changed, keeping the scan metadata.
+pub fn recount(report: &mut SecurityReport) {
+    let mut aggregated = ResultAggregator::aggregate(std::mem::take(&mut report.findings), report.scan_duration);
+    aggregated.analyzed_at = report.analyzed_at;
+    aggregated.files_scanned = report.files_scanned;
+    std::mem::swap(&mut aggregated.performance_metrics, &mut report.performance_metrics);
+    *report = aggregated;
+}
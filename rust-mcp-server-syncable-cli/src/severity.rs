@@ -0,0 +1,80 @@
+// src/severity.rs
+
+use syncable_cli::analyzer::security::SecuritySeverity;
+use syncable_cli::analyzer::vulnerability::VulnerabilitySeverity;
+
+/// Severity names from most to least severe; a rank is an index into this array.
+pub const SEVERITIES: [&str; 5] = ["critical", "high", "medium", "low", "info"];
+
+/// Orders the upstream severity enums on one scale, so the policy gate, SARIF levels,
+/// HTML report and rule overrides all agree.
+pub trait SeverityRank {
+    /// 0 for critical through 4 for info.
+    fn rank(&self) -> usize;
+
+    fn name(&self) -> &'static str {
+        SEVERITIES[self.rank()]
+    }
+}
+
+impl SeverityRank for SecuritySeverity {
+    fn rank(&self) -> usize {
+        match self {
+            SecuritySeverity::Critical => 0,
+            SecuritySeverity::High => 1,
+            SecuritySeverity::Medium => 2,
+            SecuritySeverity::Low => 3,
+            SecuritySeverity::Info => 4,
+        }
+    }
+}
+
+impl SeverityRank for VulnerabilitySeverity {
+    fn rank(&self) -> usize {
+        match self {
+            VulnerabilitySeverity::Critical => 0,
+            VulnerabilitySeverity::High => 1,
+            VulnerabilitySeverity::Medium => 2,
+            VulnerabilitySeverity::Low => 3,
+            VulnerabilitySeverity::Info => 4,
+        }
+    }
+}
+
+/// Parses a case-insensitive severity name into its rank.
+pub fn parse_severity(name: &str) -> Option<usize> {
+    let name = name.to_lowercase();
+    SEVERITIES.iter().position(|s| *s == name)
+}
+
+/// The security severity for a rank returned by [`parse_severity`] or [`SeverityRank::rank`].
+pub fn security_severity(rank: usize) -> SecuritySeverity {
+    match rank {
+        0 => SecuritySeverity::Critical,
+        1 => SecuritySeverity::High,
+        2 => SecuritySeverity::Medium,
+        3 => SecuritySeverity::Low,
+        _ => SecuritySeverity::Info,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_parse_back_to_their_rank() {
+        for severity in [
+            SecuritySeverity::Critical,
+            SecuritySeverity::High,
+            SecuritySeverity::Medium,
+            SecuritySeverity::Low,
+            SecuritySeverity::Info,
+        ] {
+            assert_eq!(parse_severity(severity.name()), Some(severity.rank()));
+            assert_eq!(security_severity(severity.rank()), severity);
+        }
+        assert_eq!(parse_severity("HIGH"), Some(VulnerabilitySeverity::High.rank()));
+        assert_eq!(parse_severity("severe"), None);
+    }
+}
@@ -0,0 +1,100 @@
+// src/test_support.rs
+//
+// Builders for reports in unit tests, shaped like the turbo analyzer's output.
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use chrono::Utc;
+use syncable_cli::analyzer::dependency_parser::Language;
+use syncable_cli::analyzer::security::turbo::results::{ResultAggregator, SecurityReport};
+use syncable_cli::analyzer::security::{SecurityCategory, SecurityFinding, SecuritySeverity};
+use syncable_cli::analyzer::vulnerability::{
+    VulnerabilityInfo, VulnerabilityReport, VulnerabilitySeverity, VulnerableDependency,
+};
+
+use crate::vuln_ignore;
+
+pub const PROJECT: &str = "/project";
+
+pub fn project() -> &'static Path {
+    Path::new(PROJECT)
+}
+
+/// A finding for `file` (relative to [`PROJECT`]) with an upstream-style id.
+pub fn finding(
+    rule: &str,
+    file: &str,
+    line: usize,
+    severity: SecuritySeverity,
+    category: SecurityCategory,
+) -> SecurityFinding {
+    let path = PathBuf::from(PROJECT).join(file);
+    SecurityFinding {
+        id: format!("{}-{}-{}", rule, path.display(), line),
+        title: format!("{} finding", rule),
+        description: format!("{} matched", rule),
+        severity,
+        category,
+        file_path: Some(path),
+        line_number: Some(line),
+        column_number: Some(1),
+        evidence: Some(format!("{} = \"***\"", rule)),
+        remediation: vec!["Move the value to a secret manager".to_string()],
+        references: vec![],
+        cwe_id: Some("CWE-798".to_string()),
+        compliance_frameworks: vec![],
+    }
+}
+
+/// A secrets finding, the most common turbo result.
+pub fn secret(rule: &str, file: &str, line: usize, severity: SecuritySeverity) -> SecurityFinding {
+    finding(rule, file, line, severity, SecurityCategory::SecretsExposure)
+}
+
+/// Aggregates `findings` into a report with totals, score and risk level filled in.
+pub fn report(findings: Vec<SecurityFinding>) -> SecurityReport {
+    let mut report = ResultAggregator::aggregate(findings, Duration::from_millis(10));
+    report.files_scanned = 1;
+    report
+}
+
+/// A vulnerability report with one dependency per `(package, advisories)` pair.
+pub fn vulnerability_report(dependencies: Vec<(&str, Vec<VulnerabilityInfo>)>) -> VulnerabilityReport {
+    let mut report = VulnerabilityReport {
+        checked_at: Utc::now(),
+        total_vulnerabilities: 0,
+        critical_count: 0,
+        high_count: 0,
+        medium_count: 0,
+        low_count: 0,
+        vulnerable_dependencies: dependencies
+            .into_iter()
+            .map(|(name, vulnerabilities)| VulnerableDependency {
+                name: name.to_string(),
+                version: "1.0.0".to_string(),
+                language: Language::JavaScript,
+                vulnerabilities,
+            })
+            .collect(),
+    };
+    vuln_ignore::recount(&mut report);
+    report
+}
+
+/// An advisory with the given id and optional CVE alias.
+pub fn advisory(id: &str, cve: Option<&str>, severity: VulnerabilitySeverity) -> VulnerabilityInfo {
+    VulnerabilityInfo {
+        id: id.to_string(),
+        vuln_type: "security".to_string(),
+        severity,
+        title: format!("{} advisory", id),
+        description: String::new(),
+        cve: cve.map(str::to_string),
+        ghsa: id.starts_with("GHSA-").then(|| id.to_string()),
+        affected_versions: "<2.0.0".to_string(),
+        patched_versions: Some(">=2.0.0".to_string()),
+        published_date: None,
+        references: vec![],
+    }
+}
@@ -5,13 +5,26 @@ use rust_mcp_sdk::{
     macros::{mcp_tool, JsonSchema},
     tool_box,
 };
-use syncable_cli::cli::SeverityThreshold;
 use std::error::Error;
 use std::fmt;
 use std::path::Path;
+use syncable_cli::analyzer::dependency_parser::{parse_detailed_dependencies, DependencyParser};
+use syncable_cli::analyzer::vulnerability::VulnerabilityChecker;
+use syncable_cli::analyzer::security::turbo::results::SecurityReport;
+use syncable_cli::analyzer::security::{ScanMode, TurboConfig, TurboSecurityAnalyzer};
+use syncable_cli::analyzer::{analyze_monorepo, AnalysisConfig};
 //use syncable_cli;
 use syncable_cli::cli::{DisplayFormat::Detailed, DisplayFormat::Matrix, DisplayFormat::Summary};
 
+use crate::dependency_scope::DependencyScope;
+use crate::policy::SeverityPolicy;
+use crate::changed_files::{self, DiffScope, ScanRoot};
+use crate::report::OutputFormat;
+use crate::rule_overrides::{self, OverrideSummary};
+use crate::security_baseline::BaselineSummary;
+use crate::security_merge::{self, MergeSummary, ScanEngine};
+use crate::{html, owasp, project_file, report, sarif, sbom, security_baseline, vuln_baseline, vuln_ignore};
+
 #[derive(Debug)]
 struct AnalyzeToolError(String);
 
@@ -52,11 +65,18 @@ impl AboutInfoTool {
             {BOLD}2. Security Scan (security_scan):{RESET}\n\
             \t{BOLD}What it does{RESET}: Scans your codebase for security risks, including exposed secrets and common vulnerabilities.\n\
             \t{BOLD}How to use{RESET}: Provide the {CYAN}path{RESET} to the project you want to scan.\n\
-            \t{BOLD}Customization{RESET}: Uses a balanced scan mode by default. Other modes like {GREEN}'lightning'{RESET} or {GREEN}'paranoid'{RESET} will be available.\n\n\
+            \t{BOLD}Customization{RESET}: Uses a balanced scan mode by default. Other modes like {GREEN}'lightning'{RESET} or {GREEN}'paranoid'{RESET} will be available. Set {CYAN}engine{RESET} to {GREEN}\"merged\"{RESET} to add the classic rule analyzer's findings, or {CYAN}format{RESET} to {GREEN}\"sarif\"{RESET} to upload results to GitHub Code Scanning or {GREEN}\"html\"{RESET} for a report to share.\n\n\
             {BOLD}3. Dependency Scan (dependency_scan):{RESET}\n\
             \t{BOLD}What it does{RESET}: Inspects your project's dependencies and checks them against known vulnerability databases.\n\
             \t{BOLD}How to use{RESET}: Specify the project {CYAN}path{RESET} to scan for dependencies.\n\
             \t{BOLD}Customization{RESET}: You can add arguments to check for licenses or filter vulnerabilities by severity level.\n\n\
+            {BOLD}4. SBOM (sbom):{RESET}\n\
+            \t{BOLD}What it does{RESET}: Generates a Software Bill of Materials listing every parsed dependency with its package URL and license.\n\
+            \t{BOLD}How to use{RESET}: Provide the {CYAN}path{RESET} to the project to inventory.\n\
+            \t{BOLD}Customization{RESET}: Set {CYAN}format{RESET} to cyclonedx (default), spdx-json or spdx-tag-value.\n\n\
+            {BOLD}5. Report Schema (report_schema):{RESET}\n\
+            \t{BOLD}What it does{RESET}: Returns the JSON Schema of the vulnerability and security reports, versioned by their {CYAN}schema_version{RESET} field.\n\
+            \t{BOLD}How to use{RESET}: Optionally set {CYAN}report{RESET} to vulnerability or security.\n\n\
             This server empowers you to maintain high standards of code quality, security, and dependency management with simple, powerful commands.\n"
         );
         Ok(CallToolResult::text_content(vec![TextContent::new(info, None, None)]))
@@ -124,7 +144,7 @@ impl AnalysisScanTool {
                     Err(e) => {
                         eprintln!("⚠️  JSON validation failed: {}", e);
                         eprintln!("First 500 chars: {}", &analysis_json_str[..std::cmp::min(500, analysis_json_str.len())]);
-                        return Err(CallToolError::new(AnalyzeToolError(format!("Invalid JSON response: {}", e))));
+                        Err(CallToolError::new(AnalyzeToolError(format!("Invalid JSON response: {}", e))))
                     }
                 }
             }
@@ -139,35 +159,90 @@ impl AnalysisScanTool {
 
 #[mcp_tool(
     name = "vulnerability_scan",
-    description = "Scans a project for known vulnerabilities."
+    description = "Scans a project for known vulnerabilities. Advisories listed in .syncable/vuln-ignore.toml are excluded from the report. With a baseline, only vulnerabilities introduced since that snapshot are reported, along with the ones fixed. Set format to sarif for a SARIF 2.1.0 log."
 )]
 
 #[derive(Debug, ::serde::Deserialize, ::serde::Serialize, JsonSchema)]
 pub struct VulnerabilityScanTool {
     path: Option<String>,
+    /// Only check production (and optional) dependencies.
+    prod_only: Option<bool>,
+    /// Only check development dependencies.
+    dev_only: Option<bool>,
+    /// A previous snapshot to compare against, as a path inside the project relative to it.
+    baseline: Option<String>,
+    /// Where to save the full report as a snapshot for later baseline comparisons, inside the project.
+    snapshot: Option<String>,
+    /// Lowest severity that fails the policy: critical, high (default), medium, low, info or none.
+    fail_on: Option<String>,
+    /// Vulnerability types that never fail the policy, e.g. `unmaintained` or `yanked`.
+    non_blocking_categories: Option<Vec<String>>,
+    /// Output format: json (default) or sarif for GitHub Code Scanning and other SARIF consumers.
+    format: Option<String>,
 }
 
 impl VulnerabilityScanTool {
     pub async fn call_tool(&self) -> Result<CallToolResult, CallToolError> {
         let project_path_str = self.path.as_deref().unwrap_or(".");
+        let scope = DependencyScope::from_flags(self.prod_only, self.dev_only)
+            .map_err(|e| CallToolError::new(AnalyzeToolError(e)))?;
+        let policy = SeverityPolicy::from_params(self.fail_on.as_deref(), self.non_blocking_categories.as_deref())
+            .map_err(|e| CallToolError::new(AnalyzeToolError(e)))?;
+        let format = OutputFormat::from_param(self.format.as_deref())
+            .map_err(|e| CallToolError::new(AnalyzeToolError(e)))?;
+        if format == OutputFormat::Html {
+            return Err(CallToolError::new(AnalyzeToolError(
+                "The html format is only available for security_scan".to_string(),
+            )));
+        }
         
         // Log to stderr so we don't interfere with MCP stdout JSON messages
         eprintln!("🛡️  Scanning project for vulnerabilities: {}", project_path_str);
-        eprintln!("➡️  Calling syncable_cli::handle_vulnerabilities...");
+        eprintln!("🛡️  Scope: {:?}", scope);
+        eprintln!("➡️  Calling VulnerabilityChecker::check_all_dependencies...");
         
+        let project_path = Path::new(project_path_str).to_path_buf();
+        let baseline_path = self
+            .baseline
+            .as_deref()
+            .map(|p| project_file::resolve(&project_path, "baseline", p))
+            .transpose()
+            .map_err(|e| CallToolError::new(AnalyzeToolError(e)))?;
+        let snapshot_path = self
+            .snapshot
+            .as_deref()
+            .map(|p| project_file::resolve(&project_path, "snapshot", p))
+            .transpose()
+            .map_err(|e| CallToolError::new(AnalyzeToolError(e)))?;
+
         let vulnerability_results = tokio::task::spawn_blocking({
-            let project_path = Path::new(project_path_str).to_path_buf();
             move || {
+                let ignore_entries = vuln_ignore::load(&project_path)?;
+                let baseline = baseline_path.as_deref().map(vuln_baseline::load).transpose()?;
+                let mut dependencies = DependencyParser::new()
+                    .parse_all_dependencies(&project_path)
+                    .map_err(|e| e.to_string())?;
+                let out_of_scope = scope.retain(&mut dependencies);
+
                 // Create a runtime for the blocking task to handle the async function
                 let rt = tokio::runtime::Runtime::new().unwrap();
-                rt.block_on(async {
-                    syncable_cli::handle_vulnerabilities(
-                        project_path,
-                        None,
-                        syncable_cli::cli::OutputFormat::Json,
-                        None,
-                    ).await
-                })
+                let mut report = rt
+                    .block_on(VulnerabilityChecker::new().check_all_dependencies(&dependencies, &project_path))
+                    .map_err(|e| e.to_string())?;
+
+                let ignores = vuln_ignore::apply(&mut report, &ignore_entries, chrono::Local::now().date_naive());
+
+                if let Some(snapshot_path) = &snapshot_path {
+                    let snapshot = serde_json::to_string_pretty(&report).map_err(|e| e.to_string())?;
+                    vuln_baseline::save_snapshot(snapshot_path, &snapshot)?;
+                    eprintln!("💾 Saved snapshot to {}", snapshot_path.display());
+                }
+                let filtered = vuln_baseline::Filtered {
+                    packages: out_of_scope,
+                    advisories: ignores.ignored.iter().map(|v| (v.package.clone(), v.id.clone())).collect(),
+                };
+                let delta = baseline.map(|baseline| vuln_baseline::apply(&mut report, &baseline, &filtered));
+                Ok::<_, String>((report, ignores, delta))
             }
         }).await;
 
@@ -177,33 +252,53 @@ impl VulnerabilityScanTool {
         };
 
         match vulnerability_results {
-            Ok(analysis) => {
-                let json_output = serde_json::to_string_pretty(&analysis).unwrap_or_else(|e| {
+            Ok((report, ignores, delta)) => {
+                if !ignores.ignored.is_empty() {
+                    eprintln!("🙈 Ignored {} finding(s) listed in {}", ignores.ignored.len(), vuln_ignore::IGNORE_FILE);
+                }
+
+                let mut output = report::versioned(serde_json::to_value(&report).unwrap_or_default());
+                output["ignored_vulnerabilities"] = serde_json::to_value(&ignores.ignored).unwrap_or_default();
+                output["expired_ignores"] = serde_json::to_value(&ignores.expired).unwrap_or_default();
+                if let Some(delta) = delta {
+                    eprintln!("📊 {} new, {} fixed since baseline", delta.new_count, delta.fixed_vulnerabilities.len());
+                    output["baseline"] = serde_json::to_value(&delta).unwrap_or_default();
+                }
+                let decision = policy.evaluate(
+                    report
+                        .vulnerable_dependencies
+                        .iter()
+                        .flat_map(|dep| &dep.vulnerabilities)
+                        .map(|vuln| (&vuln.severity, vuln.vuln_type.as_str())),
+                );
+                eprintln!("🚦 Policy {}: {} blocking finding(s)", if decision.passed { "passed" } else { "failed" }, decision.blocking_findings);
+                output["policy"] = serde_json::to_value(&decision).unwrap_or_default();
+                if format == OutputFormat::Sarif {
+                    let project_path = Path::new(project_path_str);
+                    let mut log = sarif::vulnerabilities(&report, &project_path.canonicalize().unwrap_or(project_path.to_path_buf()));
+                    log["runs"][0]["properties"] = serde_json::json!({
+                        "schema_version": output["schema_version"],
+                        "ignored_vulnerabilities": output["ignored_vulnerabilities"],
+                        "expired_ignores": output["expired_ignores"],
+                        "baseline": output.get("baseline"),
+                        "policy": output["policy"],
+                    });
+                    output = log;
+                }
+                let json_output = serde_json::to_string_pretty(&output).unwrap_or_else(|e| {
                     format!(
                         "{{\"error\": \"Failed to serialize analysis result: {}\"}}",
                         e
                     )
                 });
 
-                eprintln!("✅ handle_vulnerabilities returned ({} bytes)", json_output.len());
-
-                // Validate JSON to ensure it's well-formed
-                match serde_json::from_str::<serde_json::Value>(&json_output) {
-                    Ok(_) => {
-                        eprintln!("✅ JSON validation passed");
-                        eprintln!("📤 Sending full response ({} bytes)", json_output.len());
-                        Ok(CallToolResult::text_content(vec![TextContent::new(json_output, None, None)]))
-                    }
-                    Err(e) => {
-                        eprintln!("⚠️  JSON validation failed: {}", e);
-                        eprintln!("First 500 chars: {}", &json_output[..std::cmp::min(500, json_output.len())]);
-                        Err(CallToolError::new(AnalyzeToolError(format!("Invalid JSON response: {}", e))))
-                    }
-                }
+                eprintln!("✅ check_all_dependencies returned ({} bytes)", json_output.len());
+                eprintln!("📤 Sending full response ({} bytes)", json_output.len());
+                Ok(CallToolResult::text_content(vec![TextContent::new(json_output, None, None)]))
             }
             Err(e) => {
                 let error_message = format!("Failed to analyze project for vulnerabilities: {}", e);
-                eprintln!("❌ handle_vulnerabilities error: {}", &error_message);
+                eprintln!("❌ check_all_dependencies error: {}", &error_message);
                 Err(CallToolError::new(AnalyzeToolError(error_message)))
             }
         }
@@ -212,96 +307,294 @@ impl VulnerabilityScanTool {
 
 #[mcp_tool(
     name = "security_scan",
-    description = "Scans a project for security vulnerabilities and secret leaks."
+    description = "Scans a project for security vulnerabilities and secret leaks. With a baseline file, findings recorded in it are suppressed so only new ones are reported; set update_baseline to record or refresh it from the current findings. Rule severities can be overridden or rules disabled in .syncable/security.toml. Set engine to merged to add the classic rule analyzer's findings, or diff_base to scan only files changed since a git ref. Set format to sarif for a SARIF 2.1.0 log, or html for a self-contained HTML report."
 )]
 #[derive(Debug, ::serde::Deserialize, ::serde::Serialize, JsonSchema)]
 pub struct SecurityScanTool {
     path: Option<String>,
+    /// turbo (default) runs the fast pattern scanner; merged also runs the classic rule analyzer and drops duplicates.
+    engine: Option<String>,
+    /// Baseline of known findings to suppress, as a path inside the project relative to it.
+    baseline: Option<String>,
+    /// Record the baseline from this scan's findings instead of reading it; required when the file does not exist yet.
+    update_baseline: Option<bool>,
+    /// Only scan files added or modified since this git ref, plus uncommitted and untracked files.
+    diff_base: Option<String>,
+    /// Lowest severity that fails the policy: critical, high (default), medium, low, info or none.
+    fail_on: Option<String>,
+    /// Security categories that never fail the policy, e.g. `CodeSecurityPattern`.
+    non_blocking_categories: Option<Vec<String>>,
+    /// Output format: json (default), sarif for GitHub Code Scanning, or html for a shareable, filterable report.
+    format: Option<String>,
 }
 
 impl SecurityScanTool {
-    pub fn call_tool(&self) -> Result<CallToolResult, CallToolError> {
+    pub async fn call_tool(&self) -> Result<CallToolResult, CallToolError> {
         let project_path_str = self.path.as_deref().unwrap_or(".");
+        let policy = SeverityPolicy::from_params(self.fail_on.as_deref(), self.non_blocking_categories.as_deref())
+            .map_err(|e| CallToolError::new(AnalyzeToolError(e)))?;
+        let format = OutputFormat::from_param(self.format.as_deref())
+            .map_err(|e| CallToolError::new(AnalyzeToolError(e)))?;
+        let engine = ScanEngine::from_param(self.engine.as_deref())
+            .map_err(|e| CallToolError::new(AnalyzeToolError(e)))?;
         
         // Log to stderr so we don't interfere with MCP stdout JSON messages
         eprintln!("🔒 Scanning project for security: {}", project_path_str);
-        eprintln!("➡️  Calling syncable_cli::handle_security...");
+        eprintln!("🔒 Engine: {:?}", engine);
+        eprintln!("➡️  Calling TurboSecurityAnalyzer::analyze_project...");
         
-        let security_results = syncable_cli::handle_security(
-            Path::new(project_path_str).to_path_buf(),
-            syncable_cli::cli::SecurityScanMode::Balanced,
-            false,
-            false,
-            false,
-            false,
-            false,
-            vec![],
-            syncable_cli::cli::OutputFormat::Json,
-            None,
-            false,
-        );
-        match security_results {
-            Ok(analysis) => {
-                let json_output = serde_json::to_string_pretty(&analysis).unwrap_or_else(|e| {
-                    format!(
-                        "{{\"error\": \"Failed to serialize analysis result: {}\"}}",
-                        e
-                    )
-                });
-                
-                eprintln!("✅ handle_security returned ({} bytes)", json_output.len());
-                
-                // Validate JSON to ensure it's well-formed
-                match serde_json::from_str::<serde_json::Value>(&json_output) {
-                    Ok(_) => {
-                        eprintln!("✅ JSON validation passed");
-                        eprintln!("📤 Sending full response ({} bytes)", json_output.len());
-                        Ok(CallToolResult::text_content(vec![TextContent::new(json_output, None, None)]))
-                    }
-                    Err(e) => {
-                        eprintln!("⚠️  JSON validation failed: {}", e);
-                        eprintln!("First 500 chars: {}", &json_output[..std::cmp::min(500, json_output.len())]);
-                        return Err(CallToolError::new(AnalyzeToolError(format!("Invalid JSON response: {}", e))));
+        let project_path = Path::new(project_path_str).to_path_buf();
+        let project_path = project_path.canonicalize().unwrap_or(project_path);
+
+        // The turbo analyzer joins its own worker threads, so keep it off the async runtime.
+        let baseline_path = self
+            .baseline
+            .as_deref()
+            .map(|p| project_file::resolve(&project_path, "baseline", p))
+            .transpose()
+            .map_err(|e| CallToolError::new(AnalyzeToolError(e)))?;
+        let security_results = tokio::task::spawn_blocking({
+            let project_path = project_path.clone();
+            let update_baseline = self.update_baseline.unwrap_or(false);
+            let diff_base = self.diff_base.clone();
+            move || {
+                // A mistyped path must not silently record a baseline that suppresses every finding.
+                let baseline = match &baseline_path {
+                    Some(path) if !update_baseline => Some(security_baseline::load(path)?.ok_or_else(|| {
+                        format!("Baseline {} does not exist; set update_baseline to record it", path.display())
+                    })?),
+                    _ => None,
+                };
+                let rule_config = rule_overrides::load(&project_path)?;
+                let (scan_root, diff_scope) = match diff_base {
+                    Some(base) => {
+                        if baseline_path.is_some() && update_baseline {
+                            return Err("A baseline cannot be recorded from a diff_base scan".to_string());
+                        }
+                        let changed = changed_files::since(&project_path, &base)?;
+                        eprintln!("🔀 {} file(s) changed since {}", changed.len(), base);
+                        let scan_root = ScanRoot::staged(changed_files::stage(&project_path, &changed)?);
+                        (scan_root, Some(DiffScope { base, changed_files: changed }))
                     }
+                    None => (ScanRoot::project(&project_path), None),
+                };
+
+                // Balanced mode samples half of the non-priority files, which a diff is too small for.
+                let scan_mode = if diff_scope.is_some() { ScanMode::Thorough } else { ScanMode::Balanced };
+                let mut security_report = TurboSecurityAnalyzer::new(turbo_config(scan_mode))
+                    .and_then(|analyzer| analyzer.analyze_project(&scan_root.path))
+                    .map_err(|e| e.to_string())?;
+                scan_root.unstage_all(&mut security_report.findings, &project_path);
+
+                let mut merge_summary = None;
+                if engine == ScanEngine::Merged {
+                    eprintln!("➡️  Calling SecurityAnalyzer::analyze_security...");
+                    let mut classic = security_merge::classic_findings(&scan_root.path)?;
+                    scan_root.unstage_all(&mut classic, &project_path);
+                    let merged = security_merge::merge(&mut security_report, classic, &project_path);
+                    eprintln!("🔀 Merged {} classic finding(s), {} duplicate(s)", merged.classic_findings, merged.duplicates);
+                    merge_summary = Some(merged);
+                }
+
+                let overrides = rule_config.map(|config| {
+                    let applied = rule_overrides::apply(&mut security_report, &config);
+                    eprintln!(
+                        "🎛️  {} finding(s) re-graded, {} disabled by {}",
+                        applied.overridden_findings, applied.disabled_findings, rule_overrides::CONFIG_FILE
+                    );
+                    applied
+                });
+
+                let mut summary = None;
+                if let Some(baseline_path) = &baseline_path {
+                    let (baseline, recorded) = match baseline {
+                        Some(baseline) => (baseline, false),
+                        None => {
+                            let baseline = security_baseline::record(baseline_path, &security_report, &project_path)?;
+                            eprintln!("💾 Recorded {} finding(s) in baseline {}", baseline.findings.len(), baseline_path.display());
+                            (baseline, true)
+                        }
+                    };
+                    let delta = security_baseline::apply(
+                        &mut security_report,
+                        &baseline,
+                        &project_path,
+                        recorded,
+                        diff_scope.as_ref().map(|scope| scope.changed_files.as_slice()),
+                    );
+                    eprintln!("📊 {} new, {} suppressed by baseline", delta.new_count, delta.suppressed_count);
+                    summary = Some(delta);
                 }
+                Ok::<_, String>(SecurityScan {
+                    report: security_report,
+                    diff: diff_scope,
+                    merge: merge_summary,
+                    overrides,
+                    baseline: summary,
+                })
+            }
+        }).await;
+
+        let security_results = match security_results {
+            Ok(result) => result,
+            Err(e) => return Err(CallToolError::new(AnalyzeToolError(format!("Task panicked: {}", e)))),
+        };
+
+        match security_results {
+            Ok(scan) => {
+                let SecurityScan { report: security_report, diff, merge, overrides, baseline } = scan;
+                let mut output = report::versioned(serde_json::to_value(&security_report).unwrap_or_default());
+                owasp::annotate(&mut output, &security_report);
+                if let Some(diff) = &diff {
+                    output["diff"] = serde_json::to_value(diff).unwrap_or_default();
+                }
+                if let Some(merge) = &merge {
+                    output["merge"] = serde_json::to_value(merge).unwrap_or_default();
+                }
+                if let Some(overrides) = &overrides {
+                    output["rule_overrides"] = serde_json::to_value(overrides).unwrap_or_default();
+                }
+                if let Some(baseline) = &baseline {
+                    output["baseline"] = serde_json::to_value(baseline).unwrap_or_default();
+                }
+                let decision = policy.evaluate(
+                    security_report
+                        .findings
+                        .iter()
+                        .map(|finding| (&finding.severity, format!("{:?}", finding.category))),
+                );
+                eprintln!("🚦 Policy {}: {} blocking finding(s)", if decision.passed { "passed" } else { "failed" }, decision.blocking_findings);
+                output["policy"] = serde_json::to_value(&decision).unwrap_or_default();
+                if format == OutputFormat::Sarif {
+                    let mut log = sarif::security(&security_report, &project_path);
+                    log["runs"][0]["properties"] = serde_json::json!({
+                        "schema_version": output["schema_version"],
+                        "findings_by_owasp_top10": output["findings_by_owasp_top10"],
+                        "findings_by_cwe": output["findings_by_cwe"],
+                        "diff": output.get("diff"),
+                        "merge": output.get("merge"),
+                        "rule_overrides": output.get("rule_overrides"),
+                        "baseline": output.get("baseline"),
+                        "policy": output["policy"],
+                    });
+                    output = log;
+                }
+                let response = if format == OutputFormat::Html {
+                    let context = html::ScanContext {
+                        policy: &decision,
+                        diff: diff.as_ref(),
+                        merge: merge.as_ref(),
+                        overrides: overrides.as_ref(),
+                        baseline: baseline.as_ref(),
+                    };
+                    html::security(&security_report, &project_path, &context)
+                } else {
+                    serde_json::to_string_pretty(&output).unwrap_or_else(|e| {
+                        format!(
+                            "{{\"error\": \"Failed to serialize analysis result: {}\"}}",
+                            e
+                        )
+                    })
+                };
+                
+                eprintln!("✅ analyze_project returned {} finding(s) ({} bytes)", security_report.total_findings, response.len());
+                eprintln!("📤 Sending full response ({} bytes)", response.len());
+                Ok(CallToolResult::text_content(vec![TextContent::new(response, None, None)]))
             }
             Err(e) => {
                 let error_message = format!("Failed to analyze project for security: {}", e);
-                eprintln!("❌ handle_security error: {}", &error_message);
+                eprintln!("❌ analyze_project error: {}", &error_message);
                 Err(CallToolError::new(AnalyzeToolError(error_message)))
             }
         }
     }
 }
 
+/// Everything `security_scan` computes on the blocking pool.
+struct SecurityScan {
+    report: SecurityReport,
+    diff: Option<DiffScope>,
+    merge: Option<MergeSummary>,
+    overrides: Option<OverrideSummary>,
+    baseline: Option<BaselineSummary>,
+}
+
+/// Mirrors the turbo configuration `sync-ctl security` builds for a scan mode.
+fn turbo_config(scan_mode: ScanMode) -> TurboConfig {
+    TurboConfig {
+        scan_mode,
+        max_file_size: 10 * 1024 * 1024, // 10MB
+        worker_threads: 0, // Auto-detect
+        use_mmap: true,
+        enable_cache: true,
+        cache_size_mb: 100,
+        max_critical_findings: None,
+        timeout_seconds: Some(60),
+        skip_gitignored: true,
+        priority_extensions: [
+            "env", "key", "pem", "json", "yml", "yaml", "toml", "ini", "conf", "config", "js", "ts",
+            "py", "rs", "go",
+        ]
+        .iter()
+        .map(|ext| ext.to_string())
+        .collect(),
+        pattern_sets: vec!["default".to_string(), "aws".to_string(), "gcp".to_string()],
+    }
+}
+
 #[mcp_tool(
     name = "dependency_scan",
-    description = "Scans a project for dependencies and their vulnerabilities. Defaults to the current directory if no path is provided."
+    description = "Scans a project for dependencies and their vulnerabilities. Set prod_only or dev_only to limit the scan to production or development dependencies. Defaults to the current directory if no path is provided."
 )]
 #[derive(Debug, ::serde::Deserialize, ::serde::Serialize, JsonSchema)]
 pub struct DependencyScanTool {
     path: Option<String>,
+    /// Only include production (and optional) dependencies.
+    prod_only: Option<bool>,
+    /// Only include development dependencies.
+    dev_only: Option<bool>,
 }
 
 impl DependencyScanTool {
     pub async fn call_tool(&self) -> Result<CallToolResult, CallToolError> {
         let project_path_str = self.path.as_deref().unwrap_or(".");
+        let scope = DependencyScope::from_flags(self.prod_only, self.dev_only)
+            .map_err(|e| CallToolError::new(AnalyzeToolError(e)))?;
         
         // Log to stderr so we don't interfere with MCP stdout JSON messages
         eprintln!("📦 Scanning project for dependencies: {}", project_path_str);
-        eprintln!("➡️  Calling syncable_cli::handle_dependencies...");
+        eprintln!("📦 Scope: {:?}", scope);
+        eprintln!("➡️  Calling dependency_parser::parse_detailed_dependencies...");
         
-        let dependency_results = syncable_cli::handle_dependencies(
-            Path::new(project_path_str).to_path_buf(),
-            false,
-            false,
-            false,
-            false,
-            syncable_cli::cli::OutputFormat::Json,
-        )
-        .await;
+        let dependency_results = tokio::task::spawn_blocking({
+            let path = Path::new(project_path_str).to_path_buf();
+            move || {
+                let project_path = path.canonicalize().unwrap_or(path);
+                let monorepo_analysis = analyze_monorepo(&project_path)?;
+                let languages: Vec<_> = monorepo_analysis
+                    .projects
+                    .iter()
+                    .flat_map(|project| project.analysis.languages.clone())
+                    .collect();
+
+                // Create a runtime for the blocking task to handle the async function
+                let rt = tokio::runtime::Runtime::new().unwrap();
+                rt.block_on(parse_detailed_dependencies(&project_path, &languages, &AnalysisConfig::default()))
+            }
+        }).await;
+
+        let dependency_results = match dependency_results {
+            Ok(result) => result,
+            Err(e) => return Err(CallToolError::new(AnalyzeToolError(format!("Task panicked: {}", e)))),
+        };
+
         match dependency_results {
-            Ok(output) => {
+            Ok(mut dep_analysis) => {
+                scope.retain_detailed(&mut dep_analysis.dependencies);
+                let output = serde_json::json!({
+                    "dependencies": dep_analysis.dependencies,
+                    "total": dep_analysis.dependencies.len(),
+                });
                 let json_output = serde_json::to_string_pretty(&output).unwrap_or_else(|e| {
                     format!(
                         "{{\"error\": \"Failed to serialize analysis result: {}\"}}",
@@ -309,31 +602,129 @@ impl DependencyScanTool {
                     )
                 });
                 
-                eprintln!("✅ handle_dependencies returned ({} bytes)", json_output.len());
-                
-                // Validate JSON to ensure it's well-formed
-                match serde_json::from_str::<serde_json::Value>(&json_output) {
-                    Ok(_) => {
-                        eprintln!("✅ JSON validation passed");
-                        eprintln!("📤 Sending full response ({} bytes)", json_output.len());
-                        Ok(CallToolResult::text_content(vec![TextContent::new(json_output, None, None)]))
-                    }
-                    Err(e) => {
-                        eprintln!("⚠️  JSON validation failed: {}", e);
-                        eprintln!("First 500 chars: {}", &json_output[..std::cmp::min(500, json_output.len())]);
-                        return Err(CallToolError::new(AnalyzeToolError(format!("Invalid JSON response: {}", e))));
-                    }
-                }
+                eprintln!("✅ parse_detailed_dependencies returned ({} bytes)", json_output.len());
+                eprintln!("📤 Sending full response ({} bytes)", json_output.len());
+                Ok(CallToolResult::text_content(vec![TextContent::new(json_output, None, None)]))
             }
             Err(e) => {
                 let error_message = format!("Failed to analyze project for dependencies: {}", e);
-                eprintln!("❌ handle_dependencies error: {}", &error_message);
+                eprintln!("❌ parse_detailed_dependencies error: {}", &error_message);
                 Err(CallToolError::new(AnalyzeToolError(error_message)))
             }
         }
     }
 }
 
+// --- Tool for generating a Software Bill of Materials ---
+#[mcp_tool(
+    name = "sbom",
+    description = "Generates a Software Bill of Materials from the project's dependencies, including licenses where known. Supports CycloneDX 1.5 JSON (default) and SPDX 2.3 JSON or tag-value. Defaults to the current directory if no path is provided."
+)]
+#[derive(Debug, ::serde::Deserialize, ::serde::Serialize, JsonSchema)]
+pub struct SbomTool {
+    /// The path to the project to inventory. Defaults to the current directory.
+    path: Option<String>,
+    /// Output format: `cyclonedx` (default), `spdx-json` or `spdx-tag-value`.
+    format: Option<String>,
+}
+
+impl SbomTool {
+    pub async fn call_tool(&self) -> Result<CallToolResult, CallToolError> {
+        let project_path_str = self.path.as_deref().unwrap_or(".");
+        let format = self.format.as_deref().unwrap_or("cyclonedx");
+
+        if !matches!(format, "cyclonedx" | "spdx-json" | "spdx-tag-value") {
+            return Err(CallToolError::new(AnalyzeToolError(format!(
+                "Unsupported SBOM format '{}': expected cyclonedx, spdx-json or spdx-tag-value",
+                format
+            ))));
+        }
+
+        // Log to stderr so we don't interfere with MCP stdout JSON messages
+        eprintln!("📋 Generating SBOM for project: {}", project_path_str);
+        eprintln!("📋 Format: {}", format);
+        eprintln!("➡️  Calling DependencyParser::parse_all_dependencies...");
+
+        let project_path = Path::new(project_path_str).to_path_buf();
+        let dependencies = tokio::task::spawn_blocking({
+            let project_path = project_path.clone();
+            move || {
+                DependencyParser::new()
+                    .parse_all_dependencies(&project_path)
+                    .map(|dependencies| (dependencies, sbom::lockfile_hashes(&project_path)))
+            }
+        })
+        .await;
+
+        let dependencies = match dependencies {
+            Ok(result) => result,
+            Err(e) => return Err(CallToolError::new(AnalyzeToolError(format!("Task panicked: {}", e)))),
+        };
+        let (dependencies, hashes) = match dependencies {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                let error_message = format!("Failed to parse dependencies for SBOM: {}", e);
+                eprintln!("❌ parse_all_dependencies error: {}", &error_message);
+                return Err(CallToolError::new(AnalyzeToolError(error_message)));
+            }
+        };
+
+        let project_name = project_path
+            .canonicalize()
+            .ok()
+            .and_then(|p| p.file_name().map(|n| n.to_string_lossy().into_owned()))
+            .unwrap_or_else(|| project_path_str.to_string());
+        let output = match format {
+            "spdx-tag-value" => Ok(sbom::spdx_tag_value(&sbom::spdx_json(&project_name, &dependencies, &hashes))),
+            "spdx-json" => serde_json::to_string_pretty(&sbom::spdx_json(&project_name, &dependencies, &hashes)),
+            _ => serde_json::to_string_pretty(&sbom::cyclonedx_json(&project_name, &dependencies, &hashes)),
+        };
+
+        match output {
+            Ok(json_output) => {
+                eprintln!("📤 Sending SBOM ({} bytes)", json_output.len());
+                Ok(CallToolResult::text_content(vec![TextContent::new(json_output, None, None)]))
+            }
+            Err(e) => Err(CallToolError::new(AnalyzeToolError(format!("Failed to serialize SBOM: {}", e)))),
+        }
+    }
+}
+
+// --- Tool for publishing the report JSON Schemas ---
+#[mcp_tool(
+    name = "report_schema",
+    description = "Returns the JSON Schema of the reports produced by vulnerability_scan and security_scan, along with the current schema_version."
+)]
+#[derive(Debug, ::serde::Deserialize, ::serde::Serialize, JsonSchema)]
+pub struct ReportSchemaTool {
+    /// Which schema to return: `vulnerability` or `security`. Returns both if omitted.
+    report: Option<String>,
+}
+
+impl ReportSchemaTool {
+    pub fn call_tool(&self) -> Result<CallToolResult, CallToolError> {
+        let parse = |schema: &str| serde_json::from_str::<serde_json::Value>(schema).unwrap_or_default();
+        let output = match self.report.as_deref() {
+            Some("vulnerability") => parse(report::VULNERABILITY_REPORT_SCHEMA),
+            Some("security") => parse(report::SECURITY_REPORT_SCHEMA),
+            None => serde_json::json!({
+                "schema_version": report::SCHEMA_VERSION,
+                "vulnerability_report": parse(report::VULNERABILITY_REPORT_SCHEMA),
+                "security_report": parse(report::SECURITY_REPORT_SCHEMA),
+            }),
+            Some(other) => {
+                return Err(CallToolError::new(AnalyzeToolError(format!(
+                    "Unknown report '{}': expected vulnerability or security",
+                    other
+                ))))
+            }
+        };
+        let json_output = serde_json::to_string_pretty(&output)
+            .map_err(|e| CallToolError::new(AnalyzeToolError(format!("Failed to serialize schema: {}", e))))?;
+        Ok(CallToolResult::text_content(vec![TextContent::new(json_output, None, None)]))
+    }
+}
+
 // --- Create a Tool Box ---
 // This generates an enum `ServerTools` that contains all our defined tools.
 tool_box!(
@@ -343,6 +734,8 @@ tool_box!(
         AnalysisScanTool,
         VulnerabilityScanTool,
         SecurityScanTool,
-        DependencyScanTool
+        DependencyScanTool,
+        SbomTool,
+        ReportSchemaTool
     ]
 );
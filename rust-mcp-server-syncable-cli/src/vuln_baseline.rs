@@ -0,0 +1,215 @@
+// src/vuln_baseline.rs
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use syncable_cli::analyzer::vulnerability::{VulnerabilityReport, VulnerabilitySeverity};
+
+use crate::vuln_ignore;
+
+/// A finding present in the baseline that no longer shows up in the current scan.
+#[derive(Debug, Serialize)]
+pub struct FixedVulnerability {
+    pub package: String,
+    pub version: String,
+    pub id: String,
+    pub severity: VulnerabilitySeverity,
+    pub title: String,
+}
+
+/// Summary of the comparison against a previous snapshot.
+#[derive(Debug, Serialize)]
+pub struct BaselineDelta {
+    pub baseline_checked_at: DateTime<Utc>,
+    pub new_count: usize,
+    pub fixed_vulnerabilities: Vec<FixedVulnerability>,
+}
+
+/// Baseline findings the current scan filtered out rather than fixed: advisories ignored in
+/// `.syncable/vuln-ignore.toml` and packages outside the `prod_only`/`dev_only` scope.
+#[derive(Debug, Default)]
+pub struct Filtered {
+    pub packages: HashSet<String>,
+    pub advisories: HashSet<(String, String)>,
+}
+
+impl Filtered {
+    fn contains(&self, package: &str, id: &str) -> bool {
+        self.packages.contains(package) || self.advisories.contains(&(package.to_string(), id.to_string()))
+    }
+}
+
+/// Reads a report previously written by `vulnerability_scan` (or `sync-ctl vulnerabilities --format json`).
+pub fn load(path: &Path) -> Result<VulnerabilityReport, String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read baseline {}: {}", path.display(), e))?;
+    serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse baseline {}: {}", path.display(), e))
+}
+
+/// Persists the serialized report so later scans can use it as a baseline.
+pub fn save_snapshot(path: &Path, json: &str) -> Result<(), String> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+    }
+    std::fs::write(path, json)
+        .map_err(|e| format!("Failed to write snapshot {}: {}", path.display(), e))
+}
+
+/// Reduces `current` to findings absent from `baseline` and lists the ones that were fixed.
+///
+/// Findings are keyed by package and advisory id, so a version bump that is still
+/// affected by the same advisory is not reported as new. Findings in `filtered` were
+/// removed from `current` before the comparison, so they are not reported as fixed.
+pub fn apply(current: &mut VulnerabilityReport, baseline: &VulnerabilityReport, filtered: &Filtered) -> BaselineDelta {
+    let keys = |report: &VulnerabilityReport| -> HashSet<(String, String)> {
+        report
+            .vulnerable_dependencies
+            .iter()
+            .flat_map(|dep| dep.vulnerabilities.iter().map(|v| (dep.name.clone(), v.id.clone())))
+            .collect()
+    };
+    let baseline_keys = keys(baseline);
+    let current_keys = keys(current);
+
+    let fixed_vulnerabilities = baseline
+        .vulnerable_dependencies
+        .iter()
+        .flat_map(|dep| {
+            dep.vulnerabilities
+                .iter()
+                .filter(|v| !current_keys.contains(&(dep.name.clone(), v.id.clone())))
+                .filter(|v| !filtered.contains(&dep.name, &v.id))
+                .map(|v| FixedVulnerability {
+                    package: dep.name.clone(),
+                    version: dep.version.clone(),
+                    id: v.id.clone(),
+                    severity: v.severity.clone(),
+                    title: v.title.clone(),
+                })
+        })
+        .collect();
+
+    for dep in &mut current.vulnerable_dependencies {
+        let name = dep.name.clone();
+        dep.vulnerabilities
+            .retain(|v| !baseline_keys.contains(&(name.clone(), v.id.clone())));
+    }
+    current
+        .vulnerable_dependencies
+        .retain(|dep| !dep.vulnerabilities.is_empty());
+    vuln_ignore::recount(current);
+
+    BaselineDelta {
+        baseline_checked_at: baseline.checked_at,
+        new_count: current.total_vulnerabilities,
+        fixed_vulnerabilities,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{advisory, vulnerability_report};
+
+    #[test]
+    fn reports_new_and_fixed_advisories() {
+        let baseline = vulnerability_report(vec![
+            ("lodash", vec![advisory("GHSA-jf85-cpcp-j695", None, VulnerabilitySeverity::High)]),
+            ("minimist", vec![advisory("GHSA-vh95-rmgr-6w4m", None, VulnerabilitySeverity::Critical)]),
+        ]);
+        let mut current = vulnerability_report(vec![
+            (
+                "lodash",
+                vec![
+                    advisory("GHSA-jf85-cpcp-j695", None, VulnerabilitySeverity::High),
+                    advisory("GHSA-p6mc-m468-83gw", None, VulnerabilitySeverity::Medium),
+                ],
+            ),
+            ("axios", vec![advisory("GHSA-wf5p-g6vw-rhxx", None, VulnerabilitySeverity::Medium)]),
+        ]);
+
+        let delta = apply(&mut current, &baseline, &Filtered::default());
+        assert_eq!(delta.baseline_checked_at, baseline.checked_at);
+        assert_eq!(delta.new_count, 2);
+        assert_eq!(current.total_vulnerabilities, 2);
+        assert_eq!(current.medium_count, 2);
+        let remaining: Vec<(&str, &str)> = current
+            .vulnerable_dependencies
+            .iter()
+            .flat_map(|dep| dep.vulnerabilities.iter().map(move |v| (dep.name.as_str(), v.id.as_str())))
+            .collect();
+        assert_eq!(remaining, [("lodash", "GHSA-p6mc-m468-83gw"), ("axios", "GHSA-wf5p-g6vw-rhxx")]);
+
+        assert_eq!(delta.fixed_vulnerabilities.len(), 1);
+        let fixed = &delta.fixed_vulnerabilities[0];
+        assert_eq!((fixed.package.as_str(), fixed.id.as_str()), ("minimist", "GHSA-vh95-rmgr-6w4m"));
+        assert_eq!(fixed.severity, VulnerabilitySeverity::Critical);
+    }
+
+    #[test]
+    fn version_bumps_with_the_same_advisory_are_not_new() {
+        let baseline = vulnerability_report(vec![(
+            "lodash",
+            vec![advisory("GHSA-jf85-cpcp-j695", None, VulnerabilitySeverity::High)],
+        )]);
+        let mut current = vulnerability_report(vec![(
+            "lodash",
+            vec![advisory("GHSA-jf85-cpcp-j695", None, VulnerabilitySeverity::High)],
+        )]);
+        current.vulnerable_dependencies[0].version = "1.0.1".to_string();
+
+        let delta = apply(&mut current, &baseline, &Filtered::default());
+        assert_eq!(delta.new_count, 0);
+        assert!(delta.fixed_vulnerabilities.is_empty());
+        assert!(current.vulnerable_dependencies.is_empty());
+    }
+
+    #[test]
+    fn snapshots_round_trip_through_load() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(".syncable/vuln-baseline.json");
+        let report = vulnerability_report(vec![(
+            "lodash",
+            vec![advisory("GHSA-jf85-cpcp-j695", None, VulnerabilitySeverity::High)],
+        )]);
+
+        save_snapshot(&path, &serde_json::to_string(&report).unwrap()).unwrap();
+        let loaded = load(&path).unwrap();
+        assert_eq!(loaded.total_vulnerabilities, 1);
+        assert_eq!(loaded.vulnerable_dependencies[0].vulnerabilities[0].id, "GHSA-jf85-cpcp-j695");
+        assert!(load(&dir.path().join("missing.json")).unwrap_err().starts_with("Failed to read baseline"));
+    }
+
+    #[test]
+    fn filtered_findings_are_not_fixed() {
+        let baseline = vulnerability_report(vec![
+            ("lodash", vec![advisory("GHSA-jf85-cpcp-j695", None, VulnerabilitySeverity::High)]),
+            ("jest", vec![advisory("GHSA-vh95-rmgr-6w4m", None, VulnerabilitySeverity::Critical)]),
+            ("minimist", vec![advisory("GHSA-xvch-5gv4-984h", None, VulnerabilitySeverity::Critical)]),
+        ]);
+        // lodash's advisory is ignored and jest is a dev dependency left out of a prod_only scan.
+        let mut current = vulnerability_report(vec![(
+            "lodash",
+            vec![advisory("GHSA-jf85-cpcp-j695", None, VulnerabilitySeverity::High)],
+        )]);
+        let entries = [vuln_ignore::IgnoreEntry {
+            id: "GHSA-jf85-cpcp-j695".to_string(),
+            reason: "The affected function is not used".to_string(),
+            expires: None,
+        }];
+        let ignores = vuln_ignore::apply(&mut current, &entries, chrono::Local::now().date_naive());
+        let filtered = Filtered {
+            packages: HashSet::from(["jest".to_string()]),
+            advisories: ignores.ignored.iter().map(|v| (v.package.clone(), v.id.clone())).collect(),
+        };
+
+        let delta = apply(&mut current, &baseline, &filtered);
+        assert_eq!(delta.new_count, 0);
+        let fixed: Vec<&str> = delta.fixed_vulnerabilities.iter().map(|v| v.package.as_str()).collect();
+        assert_eq!(fixed, ["minimist"]);
+    }
+}
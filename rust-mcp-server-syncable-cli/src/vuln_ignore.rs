@@ -0,0 +1,266 @@
+// src/vuln_ignore.rs
+
+use std::path::Path;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use syncable_cli::analyzer::vulnerability::{
+    VulnerabilityInfo, VulnerabilityReport, VulnerabilitySeverity,
+};
+
+/// Allowlist location, relative to the scanned project root.
+pub const IGNORE_FILE: &str = ".syncable/vuln-ignore.toml";
+
+#[derive(Debug, Default, Deserialize)]
+struct IgnoreFile {
+    #[serde(default)]
+    ignore: Vec<IgnoreEntry>,
+}
+
+/// An allowlisted advisory, matched against the advisory id and its CVE/GHSA aliases.
+///
+/// ```toml
+/// [[ignore]]
+/// id = "RUSTSEC-2023-0071"
+/// reason = "No fix available; the affected code path is not used"
+/// expires = 2026-03-31
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+pub struct IgnoreEntry {
+    pub id: String,
+    pub reason: String,
+    #[serde(default)]
+    pub expires: Option<toml::value::Datetime>,
+}
+
+/// A finding removed from the report by an ignore entry.
+#[derive(Debug, Serialize)]
+pub struct IgnoredVulnerability {
+    pub package: String,
+    pub version: String,
+    pub id: String,
+    pub reason: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires: Option<String>,
+}
+
+/// An ignore entry past its expiry date; its advisory is reported again.
+#[derive(Debug, Serialize)]
+pub struct ExpiredIgnore {
+    pub id: String,
+    pub reason: String,
+    pub expires: String,
+}
+
+/// What the ignore file did to a report.
+#[derive(Debug, Default)]
+pub struct IgnoreOutcome {
+    pub ignored: Vec<IgnoredVulnerability>,
+    pub expired: Vec<ExpiredIgnore>,
+}
+
+/// Reads the project's ignore file; a missing file means nothing is ignored.
+pub fn load(project_path: &Path) -> Result<Vec<IgnoreEntry>, String> {
+    let path = project_path.join(IGNORE_FILE);
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = std::fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    let file: IgnoreFile = toml::from_str(&content)
+        .map_err(|e| format!("Failed to parse {}: {}", path.display(), e))?;
+    // A time without a date would otherwise read as "never expires".
+    if let Some(entry) = file.ignore.iter().find(|entry| entry.expires.is_some() && expiry_date(entry).is_none()) {
+        return Err(format!(
+            "Invalid expires '{}' for {} in {}: expected a date such as 2026-03-31",
+            entry.expires.as_ref().map(ToString::to_string).unwrap_or_default(),
+            entry.id,
+            path.display()
+        ));
+    }
+    Ok(file.ignore)
+}
+
+/// Removes findings covered by unexpired entries and recomputes the report's counts.
+///
+/// An entry still applies on its expiry date and lapses the day after.
+pub fn apply(
+    report: &mut VulnerabilityReport,
+    entries: &[IgnoreEntry],
+    today: NaiveDate,
+) -> IgnoreOutcome {
+    let mut expired = Vec::new();
+    let active: Vec<&IgnoreEntry> = entries
+        .iter()
+        .filter(|entry| match expiry_date(entry) {
+            Some(expires) if expires < today => {
+                eprintln!(
+                    "⚠️  Ignore entry {} expired on {}, reporting it again",
+                    entry.id, expires
+                );
+                expired.push(ExpiredIgnore {
+                    id: entry.id.clone(),
+                    reason: entry.reason.clone(),
+                    expires: expires.to_string(),
+                });
+                false
+            }
+            _ => true,
+        })
+        .collect();
+
+    let mut ignored = Vec::new();
+    for dep in &mut report.vulnerable_dependencies {
+        dep.vulnerabilities.retain(|vuln| {
+            match active.iter().find(|entry| matches_advisory(entry, vuln)) {
+                Some(entry) => {
+                    ignored.push(IgnoredVulnerability {
+                        package: dep.name.clone(),
+                        version: dep.version.clone(),
+                        id: vuln.id.clone(),
+                        reason: entry.reason.clone(),
+                        expires: entry.expires.map(|d| d.to_string()),
+                    });
+                    false
+                }
+                None => true,
+            }
+        });
+    }
+    report
+        .vulnerable_dependencies
+        .retain(|dep| !dep.vulnerabilities.is_empty());
+
+    recount(report);
+    IgnoreOutcome { ignored, expired }
+}
+
+fn matches_advisory(entry: &IgnoreEntry, vuln: &VulnerabilityInfo) -> bool {
+    let id = entry.id.trim();
+    vuln.id.eq_ignore_ascii_case(id)
+        || vuln.cve.as_deref().is_some_and(|cve| cve.eq_ignore_ascii_case(id))
+        || vuln.ghsa.as_deref().is_some_and(|ghsa| ghsa.eq_ignore_ascii_case(id))
+}
+
+fn expiry_date(entry: &IgnoreEntry) -> Option<NaiveDate> {
+    let date = entry.expires?.date?;
+    NaiveDate::from_ymd_opt(date.year.into(), date.month.into(), date.day.into())
+}
+
+/// Recomputes the summary counts the same way the upstream severity filter does.
+pub fn recount(report: &mut VulnerabilityReport) {
+    report.total_vulnerabilities = 0;
+    report.critical_count = 0;
+    report.high_count = 0;
+    report.medium_count = 0;
+    report.low_count = 0;
+    for vuln in report
+        .vulnerable_dependencies
+        .iter()
+        .flat_map(|dep| &dep.vulnerabilities)
+    {
+        report.total_vulnerabilities += 1;
+        match vuln.severity {
+            VulnerabilitySeverity::Critical => report.critical_count += 1,
+            VulnerabilitySeverity::High => report.high_count += 1,
+            VulnerabilitySeverity::Medium => report.medium_count += 1,
+            VulnerabilitySeverity::Low => report.low_count += 1,
+            VulnerabilitySeverity::Info => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{advisory, vulnerability_report};
+
+    fn entry(id: &str, expires: Option<&str>) -> IgnoreEntry {
+        IgnoreEntry {
+            id: id.to_string(),
+            reason: "accepted".to_string(),
+            expires: expires.map(|date| date.parse().unwrap()),
+        }
+    }
+
+    fn date(text: &str) -> NaiveDate {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn matches_advisory_id_and_aliases() {
+        let mut report = vulnerability_report(vec![
+            ("lodash", vec![advisory("GHSA-jf85-cpcp-j695", Some("CVE-2019-10744"), VulnerabilitySeverity::High)]),
+            ("minimist", vec![advisory("GHSA-vh95-rmgr-6w4m", None, VulnerabilitySeverity::Critical)]),
+            ("time", vec![advisory("RUSTSEC-2020-0071", Some("CVE-2020-26235"), VulnerabilitySeverity::Medium)]),
+            ("tar", vec![advisory("RUSTSEC-2021-0080", None, VulnerabilitySeverity::Low)]),
+        ]);
+        let entries = [
+            entry("cve-2019-10744", None),
+            entry(" GHSA-VH95-RMGR-6W4M ", None),
+            entry("RUSTSEC-2020-0071", None),
+        ];
+
+        let outcome = apply(&mut report, &entries, date("2026-01-01"));
+        let ids: Vec<&str> = outcome.ignored.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(ids, ["GHSA-jf85-cpcp-j695", "GHSA-vh95-rmgr-6w4m", "RUSTSEC-2020-0071"]);
+        assert_eq!(outcome.ignored[0].package, "lodash");
+        assert!(outcome.expired.is_empty());
+        assert_eq!(report.vulnerable_dependencies.len(), 1);
+        assert_eq!(report.total_vulnerabilities, 1);
+        assert_eq!(report.low_count, 1);
+        assert_eq!(report.critical_count + report.high_count + report.medium_count, 0);
+    }
+
+    #[test]
+    fn entries_apply_through_their_expiry_date() {
+        let entries = [entry("RUSTSEC-2020-0071", Some("2026-03-31"))];
+        let report = || {
+            vulnerability_report(vec![(
+                "time",
+                vec![advisory("RUSTSEC-2020-0071", None, VulnerabilitySeverity::Medium)],
+            )])
+        };
+
+        let mut on_expiry = report();
+        let outcome = apply(&mut on_expiry, &entries, date("2026-03-31"));
+        assert_eq!(outcome.ignored.len(), 1);
+        assert_eq!(outcome.ignored[0].expires.as_deref(), Some("2026-03-31"));
+        assert_eq!(on_expiry.total_vulnerabilities, 0);
+
+        let mut day_after = report();
+        let outcome = apply(&mut day_after, &entries, date("2026-04-01"));
+        assert!(outcome.ignored.is_empty());
+        assert_eq!(outcome.expired.len(), 1);
+        assert_eq!(outcome.expired[0].id, "RUSTSEC-2020-0071");
+        assert_eq!(outcome.expired[0].expires, "2026-03-31");
+        assert_eq!(day_after.total_vulnerabilities, 1);
+    }
+
+    #[test]
+    fn load_reads_the_ignore_file() {
+        let project = tempfile::tempdir().unwrap();
+        assert!(load(project.path()).unwrap().is_empty());
+
+        std::fs::create_dir(project.path().join(".syncable")).unwrap();
+        std::fs::write(
+            project.path().join(IGNORE_FILE),
+            "[[ignore]]\nid = \"RUSTSEC-2023-0071\"\nreason = \"unused\"\nexpires = 2026-03-31\n",
+        )
+        .unwrap();
+        let entries = load(project.path()).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(expiry_date(&entries[0]), Some(date("2026-03-31")));
+
+        std::fs::write(project.path().join(IGNORE_FILE), "[[ignore]]\nid = \"RUSTSEC-2023-0071\"\n").unwrap();
+        assert!(load(project.path()).unwrap_err().contains("reason"));
+
+        std::fs::write(
+            project.path().join(IGNORE_FILE),
+            "[[ignore]]\nid = \"RUSTSEC-2023-0071\"\nreason = \"unused\"\nexpires = 10:00:00\n",
+        )
+        .unwrap();
+        let error = load(project.path()).unwrap_err();
+        assert!(error.starts_with("Invalid expires '10:00:00' for RUSTSEC-2023-0071"), "{}", error);
+    }
+}
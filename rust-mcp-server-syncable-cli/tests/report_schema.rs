@@ -0,0 +1,135 @@
+// tests/report_schema.rs
+//
+// Guards the JSON structure of the vulnerability and security reports against the
+// published schemas, so upstream type changes show up here instead of in dashboards.
+
+mod common;
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use rust_mcp_server_syncable_cli::SCHEMA_VERSION;
+use serde_json::{json, Value};
+use syncable_cli::analyzer::dependency_parser::Language;
+use syncable_cli::analyzer::security::turbo::results::{PerformanceMetrics, SecurityReport};
+use syncable_cli::analyzer::security::{SecurityCategory, SecurityFinding, SecuritySeverity};
+use syncable_cli::analyzer::vulnerability::{
+    VulnerabilityInfo, VulnerabilityReport, VulnerabilitySeverity, VulnerableDependency,
+};
+
+use common::{assert_conforms, call_tools, project_with_secret, SECURITY_SCHEMA, VULNERABILITY_SCHEMA};
+
+#[test]
+fn vulnerability_report_serialization_matches_schema() {
+    let report = VulnerabilityReport {
+        checked_at: chrono::Utc::now(),
+        total_vulnerabilities: 1,
+        critical_count: 0,
+        high_count: 1,
+        medium_count: 0,
+        low_count: 0,
+        vulnerable_dependencies: vec![VulnerableDependency {
+            name: "lodash".to_string(),
+            version: "4.17.4".to_string(),
+            language: Language::JavaScript,
+            vulnerabilities: vec![VulnerabilityInfo {
+                id: "GHSA-jf85-cpcp-j695".to_string(),
+                vuln_type: "security".to_string(),
+                severity: VulnerabilitySeverity::High,
+                title: "Prototype Pollution in lodash".to_string(),
+                description: "Versions of lodash before 4.17.12 are vulnerable.".to_string(),
+                cve: Some("CVE-2019-10744".to_string()),
+                ghsa: Some("GHSA-jf85-cpcp-j695".to_string()),
+                affected_versions: "<4.17.12".to_string(),
+                patched_versions: Some(">=4.17.12".to_string()),
+                published_date: Some(chrono::Utc::now()),
+                references: vec!["https://github.com/advisories/GHSA-jf85-cpcp-j695".to_string()],
+            }],
+        }],
+    };
+
+    let mut output = serde_json::to_value(&report).unwrap();
+    output["schema_version"] = json!(SCHEMA_VERSION);
+    output["ignored_vulnerabilities"] = json!([{
+        "package": "minimist",
+        "version": "1.2.0",
+        "id": "GHSA-vh95-rmgr-6w4m",
+        "reason": "Only used by build tooling",
+        "expires": "2026-03-31"
+    }]);
+    output["expired_ignores"] = json!([{
+        "id": "RUSTSEC-2023-0071",
+        "reason": "No fix available",
+        "expires": "2025-12-31"
+    }]);
+    assert_conforms(&output, VULNERABILITY_SCHEMA);
+}
+
+#[test]
+fn security_report_serialization_matches_schema() {
+    let finding = SecurityFinding {
+        id: "aws-access-key".to_string(),
+        title: "AWS Access Key".to_string(),
+        description: "Hardcoded AWS access key".to_string(),
+        severity: SecuritySeverity::Critical,
+        category: SecurityCategory::SecretsExposure,
+        file_path: Some(".env".into()),
+        line_number: Some(3),
+        column_number: Some(19),
+        evidence: Some("AWS_ACCESS_KEY_ID=AKIA...".to_string()),
+        remediation: vec!["Move the key to a secret manager".to_string()],
+        references: vec![],
+        cwe_id: Some("CWE-798".to_string()),
+        compliance_frameworks: vec!["SOC2".to_string()],
+    };
+    let report = SecurityReport {
+        analyzed_at: chrono::Utc::now(),
+        scan_duration: Duration::from_millis(120),
+        overall_score: 75.0,
+        risk_level: SecuritySeverity::Critical,
+        total_findings: 1,
+        files_scanned: 4,
+        findings_by_severity: HashMap::from([(SecuritySeverity::Critical, 1)]),
+        findings_by_category: HashMap::from([(SecurityCategory::SecretsExposure, 1)]),
+        findings: vec![finding],
+        recommendations: vec!["Rotate exposed credentials".to_string()],
+        performance_metrics: PerformanceMetrics {
+            total_duration: Duration::from_millis(120),
+            file_discovery_time: Duration::from_millis(10),
+            pattern_matching_time: Duration::from_millis(100),
+            files_per_second: 33.3,
+            cache_hit_rate: 0.0,
+            memory_usage_mb: 0.0,
+        },
+    };
+
+    let mut output = serde_json::to_value(&report).unwrap();
+    output["schema_version"] = json!(SCHEMA_VERSION);
+    assert_conforms(&output, SECURITY_SCHEMA);
+}
+
+#[test]
+fn tools_return_versioned_reports_matching_schema() {
+    let project = project_with_secret();
+    let path = project.path().to_string_lossy().to_string();
+
+    let results = call_tools(&[
+        ("report_schema", json!({})),
+        ("vulnerability_scan", json!({ "path": path })),
+        ("security_scan", json!({ "path": path })),
+    ]);
+
+    let schemas: Value = serde_json::from_str(&results[0]).unwrap();
+    assert_eq!(schemas["schema_version"], SCHEMA_VERSION);
+    assert_eq!(schemas["vulnerability_report"], serde_json::from_str::<Value>(VULNERABILITY_SCHEMA).unwrap());
+    assert_eq!(schemas["security_report"], serde_json::from_str::<Value>(SECURITY_SCHEMA).unwrap());
+
+    let vulnerability_report: Value = serde_json::from_str(&results[1]).unwrap();
+    assert_eq!(vulnerability_report["schema_version"], SCHEMA_VERSION);
+    assert_conforms(&vulnerability_report, VULNERABILITY_SCHEMA);
+
+    let security_report: Value = serde_json::from_str(&results[2]).unwrap();
+    assert_eq!(security_report["schema_version"], SCHEMA_VERSION);
+    assert!(security_report["total_findings"].as_u64().unwrap() > 0);
+    assert_conforms(&security_report, SECURITY_SCHEMA);
+}
@@ -0,0 +1,178 @@
+// tests/security_scan.rs
+//
+// End-to-end checks of `security_scan` options that combine the scanner with project
+// state on disk: baselines, git diffs, rule configuration and the merged engine.
+
+mod common;
+
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+use serde_json::{json, Value};
+
+use common::{
+    assert_conforms, call_tools, project_with_secret, try_call_tools, write, CACHE_SECRET, DB_SECRET, ENV_SECRET,
+    SECURITY_SCHEMA,
+};
+
+fn scan(arguments: Value) -> Value {
+    serde_json::from_str(&call_tools(&[("security_scan", arguments)])[0]).unwrap()
+}
+
+fn git(project_path: &Path, args: &[&str]) {
+    let status = Command::new("git")
+        .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+        .args(args)
+        .current_dir(project_path)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .expect("git is installed");
+    assert!(status.success(), "git {:?} failed", args);
+}
+
+#[test]
+fn security_baseline_suppresses_recorded_findings() {
+    let project = project_with_secret();
+    let arguments = json!({
+        "path": project.path().to_string_lossy(),
+        "baseline": ".syncable/security-baseline.json"
+    });
+
+    // Without update_baseline a missing file is an error, not a fresh baseline.
+    let missing = try_call_tools(&[("security_scan", arguments.clone())]).remove(0).unwrap_err();
+    assert!(missing.contains("does not exist"), "{}", missing);
+    let outside = json!({ "path": project.path().to_string_lossy(), "baseline": "../baseline.json", "update_baseline": true });
+    let escaping = try_call_tools(&[("security_scan", outside)]).remove(0).unwrap_err();
+    assert!(escaping.contains("Invalid baseline '../baseline.json'"), "{}", escaping);
+    assert!(!project.path().join(".syncable/security-baseline.json").exists());
+
+    let mut record = arguments.clone();
+    record["update_baseline"] = json!(true);
+    let first = scan(record);
+    assert_eq!(first["baseline"]["recorded"], true);
+    assert_eq!(first["total_findings"], 0);
+    assert!(project.path().join(".syncable/security-baseline.json").exists());
+
+    // Moving the known secret down a few lines must not resurface it.
+    write(project.path(), "config.js", &format!("// settings\n\n\n{}{}", DB_SECRET, CACHE_SECRET));
+    let second = scan(arguments);
+    assert_eq!(second["baseline"]["recorded"], false);
+    assert!(second["baseline"]["suppressed_count"].as_u64().unwrap() > 0);
+    assert!(second["total_findings"].as_u64().unwrap() > 0);
+    assert_eq!(second["baseline"]["new_count"], second["total_findings"]);
+    assert!(second["findings"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .all(|finding| finding["evidence"].as_str().unwrap_or_default().contains("cache")));
+    assert_conforms(&second, SECURITY_SCHEMA);
+}
+
+#[test]
+fn security_scan_limits_to_files_changed_since_diff_base() {
+    let project = project_with_secret();
+    let project_path = project.path().canonicalize().unwrap();
+    git(&project_path, &["init", "-q"]);
+    git(&project_path, &["add", "."]);
+    git(&project_path, &["commit", "-q", "-m", "initial"]);
+    write(&project_path, "src/cache.js", CACHE_SECRET);
+
+    let report = scan(json!({ "path": project_path.to_string_lossy(), "diff_base": "HEAD" }));
+    assert_eq!(report["diff"]["changed_files"], json!(["src/cache.js"]));
+    let findings = report["findings"].as_array().unwrap();
+    assert!(!findings.is_empty());
+    let expected = project_path.join("src/cache.js").to_string_lossy().to_string();
+    assert!(findings.iter().all(|finding| finding["file_path"] == expected));
+    assert_conforms(&report, SECURITY_SCHEMA);
+}
+
+#[test]
+fn security_scan_applies_rule_overrides() {
+    let project = project_with_secret();
+    write(
+        project.path(),
+        ".syncable/security.toml",
+        "[severity_overrides]\n\"database-url-with-creds\" = \"low\"\n",
+    );
+
+    let report = scan(json!({ "path": project.path().to_string_lossy() }));
+    assert_eq!(report["rule_overrides"]["overridden_findings"], 1);
+    assert_eq!(report["findings"][0]["severity"], "Low");
+    assert_eq!(report["risk_level"], "Low");
+    assert_eq!(report["policy"]["passed"], true);
+    assert_conforms(&report, SECURITY_SCHEMA);
+}
+
+#[test]
+fn security_scan_overrides_classic_rules() {
+    let project = project_with_secret();
+    write(project.path(), ".env", ENV_SECRET);
+    write(project.path(), ".syncable/security.toml", "[severity_overrides]\n\"env-api_key\" = \"info\"\n");
+    let path = project.path().to_string_lossy().to_string();
+
+    let report = scan(json!({ "path": path, "engine": "merged" }));
+    assert_eq!(report["rule_overrides"]["overridden_findings"], 1);
+    let findings = report["findings"].as_array().unwrap();
+    let classic = findings.iter().find(|finding| finding["id"] == "env-api_key").unwrap();
+    assert_eq!(classic["severity"], "Info");
+    assert_conforms(&report, SECURITY_SCHEMA);
+
+    write(project.path(), ".syncable/security.toml", "disabled_rules = [\"env-api_key\"]\n");
+    let report = scan(json!({ "path": path, "engine": "merged" }));
+    assert_eq!(report["rule_overrides"]["disabled_findings"], 1);
+    assert!(report["findings"].as_array().unwrap().iter().all(|finding| finding["id"] != "env-api_key"));
+}
+
+#[test]
+fn security_scan_merges_engines_and_maps_owasp() {
+    let project = project_with_secret();
+    let path = project.path().to_string_lossy().to_string();
+
+    let results = call_tools(&[
+        ("security_scan", json!({ "path": path })),
+        ("security_scan", json!({ "path": path, "engine": "merged" })),
+    ]);
+
+    let turbo: Value = serde_json::from_str(&results[0]).unwrap();
+    assert_eq!(turbo["policy"]["fail_on"], "high");
+    assert_eq!(turbo["findings"][0]["owasp_top10"], "A07:2021-Identification and Authentication Failures");
+    assert!(turbo["findings_by_owasp_top10"].as_object().is_some_and(|counts| !counts.is_empty()));
+
+    let merged: Value = serde_json::from_str(&results[1]).unwrap();
+    assert_eq!(merged["merge"]["turbo_findings"], turbo["total_findings"]);
+    assert!(merged["total_findings"].as_u64() >= turbo["total_findings"].as_u64());
+    assert_conforms(&merged, SECURITY_SCHEMA);
+}
+
+#[test]
+fn security_scan_formats_keep_evidence_masked() {
+    let project = project_with_secret();
+    write(project.path(), ".env", ENV_SECRET);
+    let path = project.path().to_string_lossy().to_string();
+
+    let results = call_tools(&[
+        ("security_scan", json!({ "path": path, "format": "sarif" })),
+        ("security_scan", json!({ "path": path, "format": "html" })),
+        ("vulnerability_scan", json!({ "path": path, "format": "sarif" })),
+        ("security_scan", json!({ "path": path, "engine": "merged" })),
+        ("security_scan", json!({ "path": path, "engine": "merged", "format": "sarif" })),
+        ("security_scan", json!({ "path": path, "engine": "merged", "format": "html" })),
+    ]);
+
+    let sarif: Value = serde_json::from_str(&results[0]).unwrap();
+    let run = &sarif["runs"][0];
+    let location = &run["results"][0]["locations"][0]["physicalLocation"];
+    assert_eq!(location["artifactLocation"]["uri"], "config.js");
+    assert_eq!(run["properties"]["policy"]["fail_on"], "high");
+    assert!(results[1].starts_with("<!DOCTYPE html>"));
+    assert!(results[1].contains("Policy failed"));
+    assert!(
+        results.iter().all(|output| !output.contains("hunter2") && !output.contains("4eC39HqLyjWDarjtT1zdp7dc")),
+        "evidence must stay masked"
+    );
+    let merged: Value = serde_json::from_str(&results[3]).unwrap();
+    assert!(merged["merge"]["classic_findings"].as_u64().unwrap() > 0);
+    let vulnerabilities: Value = serde_json::from_str(&results[2]).unwrap();
+    assert_eq!(vulnerabilities["version"], "2.1.0");
+}